@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use morsewave::MorseCodec;
+use std::hint::black_box;
+
+const TEXT: &str = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG 0123456789";
+
+fn encode(c: &mut Criterion) {
+    let codec = MorseCodec::new();
+
+    c.bench_function("encode", |b| b.iter(|| codec.encode(black_box(TEXT))));
+
+    let mut out = String::new();
+    c.bench_function("encode_into", |b| {
+        b.iter(|| codec.encode_into(black_box(TEXT), &mut out))
+    });
+}
+
+fn decode(c: &mut Criterion) {
+    let codec = MorseCodec::new();
+    let morse = codec.encode(TEXT);
+
+    c.bench_function("decode", |b| b.iter(|| codec.decode(black_box(&morse))));
+
+    // About a megabyte of logged Morse.
+    let large = vec![morse.as_str(); 4096].join(" / ");
+    let mut group = c.benchmark_group("decode_large");
+    group.throughput(Throughput::Bytes(large.len() as u64));
+    group.sample_size(20);
+    group.bench_function("decode", |b| b.iter(|| codec.decode(black_box(&large))));
+    group.finish();
+}
+
+criterion_group!(benches, encode, decode);
+criterion_main!(benches);
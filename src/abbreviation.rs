@@ -0,0 +1,60 @@
+//! Common Q-codes and CW abbreviations.
+
+/// Abbreviations and their plain-language meanings.
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("73", "best regards"),
+    ("88", "love and kisses"),
+    ("ABT", "about"),
+    ("AGN", "again"),
+    ("ANT", "antenna"),
+    ("BCNU", "be seeing you"),
+    ("CQ", "calling any station"),
+    ("CUL", "see you later"),
+    ("DE", "from"),
+    ("ES", "and"),
+    ("FB", "fine business"),
+    ("GA", "good afternoon"),
+    ("GE", "good evening"),
+    ("GM", "good morning"),
+    ("GN", "good night"),
+    ("HR", "here"),
+    ("HW", "how"),
+    ("NR", "number"),
+    ("OM", "old man"),
+    ("OP", "operator"),
+    ("PSE", "please"),
+    ("QRL", "frequency in use"),
+    ("QRM", "interference"),
+    ("QRN", "static noise"),
+    ("QRO", "high power"),
+    ("QRP", "low power"),
+    ("QRQ", "send faster"),
+    ("QRS", "send slower"),
+    ("QRT", "stop transmitting"),
+    ("QRV", "ready"),
+    ("QRX", "wait"),
+    ("QRZ", "who is calling me"),
+    ("QSB", "fading"),
+    ("QSL", "acknowledged"),
+    ("QSO", "contact"),
+    ("QSY", "change frequency"),
+    ("QTH", "location"),
+    ("RIG", "equipment"),
+    ("RPT", "repeat"),
+    ("RST", "signal report"),
+    ("SRI", "sorry"),
+    ("TNX", "thanks"),
+    ("TU", "thank you"),
+    ("UR", "your"),
+    ("WX", "weather"),
+    ("XYL", "wife"),
+    ("YL", "young lady"),
+];
+
+/// Returns the meaning of an abbreviation, matched case-insensitively.
+pub(crate) fn expand(token: &str) -> Option<&'static str> {
+    ABBREVIATIONS
+        .iter()
+        .find(|&&(abbreviation, _)| abbreviation.eq_ignore_ascii_case(token))
+        .map(|&(_, meaning)| meaning)
+}
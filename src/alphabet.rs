@@ -0,0 +1,452 @@
+//! Morse code alphabets and their character tables.
+
+use alloc::string::String;
+
+/// A Morse code alphabet selectable on [`MorseCodec`](crate::MorseCodec).
+///
+/// Each alphabet has its own independent character table, so the same
+/// Morse sequence can decode to different characters depending on the
+/// selected alphabet.
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::{Alphabet, MorseCodec};
+///
+/// let codec = MorseCodec::with_alphabet(Alphabet::Wabun);
+/// assert_eq!(codec.encode("イロ"), ".- .-.-");
+///
+/// let greek = MorseCodec::with_alphabet(Alphabet::Greek);
+/// assert_eq!(greek.encode("ΩΨ"), ".-- --.-");
+/// assert_eq!(greek.decode(&greek.encode("αβγ")), "ΑΒΓ");
+///
+/// let cyrillic = MorseCodec::with_alphabet(Alphabet::Cyrillic);
+/// assert_eq!(cyrillic.encode("Ж"), "...-");
+/// assert_eq!(cyrillic.decode(&cyrillic.encode("привет")), "ПРИВЕТ");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alphabet {
+    /// International Morse Code (ITU-R M.1677-1).
+    ///
+    /// Includes the common accented Latin letters (Ä, À, Ç, È, É, Ñ, Ö, Ü).
+    /// The CH digraph is not mapped, since it would change how ordinary
+    /// words containing C and H are encoded.
+    #[default]
+    International,
+    /// Wabun code for Japanese katakana.
+    ///
+    /// Hiragana input is folded to katakana. Voiced kana are sent as the
+    /// base kana followed by a separate dakuten (゛) or handakuten (゜)
+    /// sign, and recombined when decoding.
+    Wabun,
+    /// American (Railroad) Morse code.
+    ///
+    /// Some characters contain intra-character spaces, e.g. C is `.. .`,
+    /// and L and 0 use long dashes written as `⸺` and `⸻`. When decoding,
+    /// adjacent tokens that together form a spaced character are joined,
+    /// so `.. .` decodes to C rather than IE. Written American Morse cannot
+    /// otherwise tell the two apart.
+    American,
+    /// Greek Morse code.
+    Greek,
+    /// Russian (Cyrillic) Morse code.
+    ///
+    /// Ё is sent as Е, as is customary in Russian telegraphy.
+    Cyrillic,
+}
+
+impl Alphabet {
+    /// Returns the character-to-Morse table for this alphabet.
+    pub(crate) fn table(self) -> &'static [(char, &'static str)] {
+        match self {
+            Alphabet::International => INTERNATIONAL,
+            Alphabet::Wabun => WABUN,
+            Alphabet::American => AMERICAN,
+            Alphabet::Greek => GREEK,
+            Alphabet::Cyrillic => CYRILLIC,
+        }
+    }
+
+    /// Returns true if some codes in this alphabet contain spaces.
+    pub(crate) fn has_spaced_codes(self) -> bool {
+        self == Alphabet::American
+    }
+
+    /// Returns true if [`compose`](Self::compose) can merge a decoded
+    /// character with the one after it.
+    pub(crate) fn has_composed_marks(self) -> bool {
+        self == Alphabet::Wabun
+    }
+
+    /// Splits a character into the symbols the alphabet transmits for it.
+    ///
+    /// Most characters map to themselves; Wabun voiced kana expand to the
+    /// base kana followed by their voicing mark.
+    pub(crate) fn decompose(self, ch: char) -> (char, Option<char>) {
+        match self {
+            Alphabet::Wabun => wabun_decompose(ch),
+            Alphabet::Cyrillic if ch == 'Ё' => ('Е', None),
+            _ => (ch, None),
+        }
+    }
+
+    /// Reverses [`decompose`](Self::decompose) on decoded text.
+    pub(crate) fn compose(self, text: String) -> String {
+        match self {
+            Alphabet::Wabun => wabun_compose(&text),
+            _ => text,
+        }
+    }
+}
+
+/// Letters that can be typed as a base letter plus a combining mark, as
+/// (base, mark, precomposed) triples.
+const COMBINING: &[(char, char, char)] = &[
+    ('A', '\u{300}', 'À'),
+    ('A', '\u{308}', 'Ä'),
+    ('C', '\u{327}', 'Ç'),
+    ('E', '\u{300}', 'È'),
+    ('E', '\u{301}', 'É'),
+    ('N', '\u{303}', 'Ñ'),
+    ('O', '\u{308}', 'Ö'),
+    ('U', '\u{308}', 'Ü'),
+    ('Е', '\u{308}', 'Ё'),
+    ('И', '\u{306}', 'Й'),
+];
+
+/// Combines an uppercase base letter with a following combining mark.
+///
+/// Returns the precomposed letter, so decomposed input such as
+/// `"E\u{301}"` encodes the same as `"É"`.
+pub(crate) fn compose_mark(base: char, mark: char) -> Option<char> {
+    COMBINING
+        .iter()
+        .find(|&&(b, m, _)| b == base && m == mark)
+        .map(|&(_, _, composed)| composed)
+}
+
+const INTERNATIONAL: &[(char, &str)] = &[
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('!', "-.-.--"),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('_', "..--.-"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('@', ".--.-."),
+    ('Ä', ".-.-"),
+    ('À', ".--.-"),
+    ('Ç', "-.-.."),
+    ('È', ".-..-"),
+    ('É', "..-.."),
+    ('Ñ', "--.--"),
+    ('Ö', "---."),
+    ('Ü', "..--"),
+    (' ', "/"),
+];
+
+const AMERICAN: &[(char, &str)] = &[
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', ".. ."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', ".-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', "-.-."),
+    ('K', "-.-"),
+    ('L', "⸺"),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', ". ."),
+    ('P', "....."),
+    ('Q', "..-."),
+    ('R', ". .."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', ".-.."),
+    ('Y', ".. .."),
+    ('Z', "... ."),
+    ('1', ".--."),
+    ('2', "..-.."),
+    ('3', "...-."),
+    ('4', "....-"),
+    ('5', "---"),
+    ('6', "......"),
+    ('7', "--.."),
+    ('8', "-...."),
+    ('9', "-..-"),
+    ('0', "⸻"),
+    ('&', ". ..."),
+    (',', ".-.-"),
+    ('.', "..--.."),
+    ('?', "-..-."),
+    ('!', "---."),
+    (' ', "/"),
+];
+
+const GREEK: &[(char, &str)] = &[
+    ('Α', ".-"),
+    ('Β', "-..."),
+    ('Γ', "--."),
+    ('Δ', "-.."),
+    ('Ε', "."),
+    ('Ζ', "--.."),
+    ('Η', "...."),
+    ('Θ', "-.-."),
+    ('Ι', ".."),
+    ('Κ', "-.-"),
+    ('Λ', ".-.."),
+    ('Μ', "--"),
+    ('Ν', "-."),
+    ('Ξ', "-..-"),
+    ('Ο', "---"),
+    ('Π', ".--."),
+    ('Ρ', ".-."),
+    ('Σ', "..."),
+    ('Τ', "-"),
+    ('Υ', "-.--"),
+    ('Φ', "..-."),
+    ('Χ', "----"),
+    ('Ψ', "--.-"),
+    ('Ω', ".--"),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    (' ', "/"),
+];
+
+const CYRILLIC: &[(char, &str)] = &[
+    ('А', ".-"),
+    ('Б', "-..."),
+    ('В', ".--"),
+    ('Г', "--."),
+    ('Д', "-.."),
+    ('Е', "."),
+    ('Ж', "...-"),
+    ('З', "--.."),
+    ('И', ".."),
+    ('Й', ".---"),
+    ('К', "-.-"),
+    ('Л', ".-.."),
+    ('М', "--"),
+    ('Н', "-."),
+    ('О', "---"),
+    ('П', ".--."),
+    ('Р', ".-."),
+    ('С', "..."),
+    ('Т', "-"),
+    ('У', "..-"),
+    ('Ф', "..-."),
+    ('Х', "...."),
+    ('Ц', "-.-."),
+    ('Ч', "---."),
+    ('Ш', "----"),
+    ('Щ', "--.-"),
+    ('Ъ', "--.--"),
+    ('Ы', "-.--"),
+    ('Ь', "-..-"),
+    ('Э', "..-.."),
+    ('Ю', "..--"),
+    ('Я', ".-.-"),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    (' ', "/"),
+];
+
+const DAKUTEN: char = '゛';
+const HANDAKUTEN: char = '゜';
+
+const WABUN: &[(char, &str)] = &[
+    ('ア', "--.--"),
+    ('イ', ".-"),
+    ('ウ', "..-"),
+    ('エ', "-.---"),
+    ('オ', ".-..."),
+    ('カ', ".-.."),
+    ('キ', "-.-.."),
+    ('ク', "...-"),
+    ('ケ', "-.--"),
+    ('コ', "----"),
+    ('サ', "-.-.-"),
+    ('シ', "--.-."),
+    ('ス', "---.-"),
+    ('セ', ".---."),
+    ('ソ', "---."),
+    ('タ', "-."),
+    ('チ', "..-."),
+    ('ツ', ".--."),
+    ('テ', ".-.--"),
+    ('ト', "..-.."),
+    ('ナ', ".-."),
+    ('ニ', "-.-."),
+    ('ヌ', "...."),
+    ('ネ', "--.-"),
+    ('ノ', "..--"),
+    ('ハ', "-..."),
+    ('ヒ', "--..-"),
+    ('フ', "--.."),
+    ('ヘ', "."),
+    ('ホ', "-.."),
+    ('マ', "-..-"),
+    ('ミ', "..-.-"),
+    ('ム', "-"),
+    ('メ', "-...-"),
+    ('モ', "-..-."),
+    ('ヤ', ".--"),
+    ('ユ', "-..--"),
+    ('ヨ', "--"),
+    ('ラ', "..."),
+    ('リ', "--."),
+    ('ル', "-.--."),
+    ('レ', "---"),
+    ('ロ', ".-.-"),
+    ('ワ', "-.-"),
+    ('ヰ', ".-..-"),
+    ('ヱ', ".--.."),
+    ('ヲ', ".---"),
+    ('ン', ".-.-."),
+    (DAKUTEN, ".."),
+    (HANDAKUTEN, "..--."),
+    ('ー', ".--.-"),
+    ('、', ".-.-.-"),
+    ('」', ".-.-.."),
+    ('（', "-.--.-"),
+    ('）', ".-..-."),
+    (' ', "/"),
+];
+
+/// Voiced kana as (composed, base, mark) triples.
+const WABUN_VOICED: &[(char, char, char)] = &[
+    ('ガ', 'カ', DAKUTEN),
+    ('ギ', 'キ', DAKUTEN),
+    ('グ', 'ク', DAKUTEN),
+    ('ゲ', 'ケ', DAKUTEN),
+    ('ゴ', 'コ', DAKUTEN),
+    ('ザ', 'サ', DAKUTEN),
+    ('ジ', 'シ', DAKUTEN),
+    ('ズ', 'ス', DAKUTEN),
+    ('ゼ', 'セ', DAKUTEN),
+    ('ゾ', 'ソ', DAKUTEN),
+    ('ダ', 'タ', DAKUTEN),
+    ('ヂ', 'チ', DAKUTEN),
+    ('ヅ', 'ツ', DAKUTEN),
+    ('デ', 'テ', DAKUTEN),
+    ('ド', 'ト', DAKUTEN),
+    ('バ', 'ハ', DAKUTEN),
+    ('ビ', 'ヒ', DAKUTEN),
+    ('ブ', 'フ', DAKUTEN),
+    ('ベ', 'ヘ', DAKUTEN),
+    ('ボ', 'ホ', DAKUTEN),
+    ('ヴ', 'ウ', DAKUTEN),
+    ('パ', 'ハ', HANDAKUTEN),
+    ('ピ', 'ヒ', HANDAKUTEN),
+    ('プ', 'フ', HANDAKUTEN),
+    ('ペ', 'ヘ', HANDAKUTEN),
+    ('ポ', 'ホ', HANDAKUTEN),
+];
+
+fn wabun_decompose(ch: char) -> (char, Option<char>) {
+    // Hiragana sits exactly 0x60 below the matching katakana.
+    let ch = match ch {
+        '\u{3041}'..='\u{3096}' => char::from_u32(ch as u32 + 0x60).unwrap_or(ch),
+        // Combining voicing marks are sent like the spacing ones.
+        '\u{3099}' => DAKUTEN,
+        '\u{309A}' => HANDAKUTEN,
+        _ => ch,
+    };
+
+    WABUN_VOICED
+        .iter()
+        .find(|&&(voiced, _, _)| voiced == ch)
+        .map_or((ch, None), |&(_, base, mark)| (base, Some(mark)))
+}
+
+fn wabun_compose(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        let voiced = chars.peek().and_then(|&mark| {
+            WABUN_VOICED
+                .iter()
+                .find(|&&(_, base, m)| base == ch && m == mark)
+                .map(|&(voiced, _, _)| voiced)
+        });
+
+        match voiced {
+            Some(voiced) => {
+                out.push(voiced);
+                chars.next();
+            }
+            None => out.push(ch),
+        }
+    }
+
+    out
+}
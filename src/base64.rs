@@ -0,0 +1,52 @@
+//! URL-safe base64 without padding, for compact shareable strings.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes bytes as URL-safe base64 without padding.
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let bits = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+
+        // n input bytes produce n + 1 output characters.
+        for i in 0..=chunk.len() {
+            let index = (bits >> (18 - 6 * i)) & 0x3F;
+            out.push(char::from(ALPHABET[index as usize]));
+        }
+    }
+
+    out
+}
+
+/// Decodes URL-safe base64 without padding.
+///
+/// Returns the byte offset of the first invalid character on failure, or
+/// the input length if the input is truncated.
+pub(crate) fn decode(text: &str) -> Result<Vec<u8>, usize> {
+    let mut out = Vec::with_capacity(text.len() / 4 * 3 + 2);
+
+    for (chunk_index, chunk) in text.as_bytes().chunks(4).enumerate() {
+        if chunk.len() == 1 {
+            return Err(text.len());
+        }
+
+        let mut bits = 0u32;
+        for (i, &byte) in chunk.iter().enumerate() {
+            let value = ALPHABET
+                .iter()
+                .position(|&c| c == byte)
+                .ok_or(chunk_index * 4 + i)?;
+            bits |= (value as u32) << (18 - 6 * i);
+        }
+
+        out.extend_from_slice(&bits.to_be_bytes()[1..chunk.len()]);
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,199 @@
+//! Memoizing wrapper around [`MorseCodec`].
+
+use crate::MorseCodec;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+
+/// Hit and miss counts of a [`CachingCodec`].
+///
+/// # Fields
+///
+/// * `hits` - Conversions answered from the cache
+/// * `misses` - Conversions that had to be computed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Returns the fraction of conversions answered from the cache.
+    ///
+    /// # Returns
+    ///
+    /// Hit rate between 0.0 and 1.0 (0.0 before any conversion)
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// A [`MorseCodec`] that remembers its most recent results.
+///
+/// Live UIs often convert the same string over and over as the user types.
+/// This wrapper keeps the most recently used encode and decode results in
+/// least-recently-used caches of a fixed capacity each.
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::{CachingCodec, MorseCodec};
+///
+/// let mut cached = CachingCodec::new(MorseCodec::new(), 2);
+/// assert_eq!(cached.decode("... --- ..."), "SOS");
+/// assert_eq!(cached.decode("... --- ..."), "SOS");
+/// assert_eq!(cached.encode("HI"), ".... ..");
+///
+/// let stats = cached.stats();
+/// assert_eq!((stats.hits, stats.misses), (1, 2));
+/// ```
+pub struct CachingCodec {
+    codec: MorseCodec,
+    encoded: Lru,
+    decoded: Lru,
+    stats: CacheStats,
+}
+
+impl CachingCodec {
+    /// Wraps a codec with encode and decode caches.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The codec that performs conversions on a cache miss
+    /// * `capacity` - Maximum number of results kept per direction
+    ///   (0 disables caching)
+    pub fn new(codec: MorseCodec, capacity: usize) -> Self {
+        CachingCodec {
+            codec,
+            encoded: Lru::new(capacity),
+            decoded: Lru::new(capacity),
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Encodes text, reusing a cached result when available.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    ///
+    /// # Returns
+    ///
+    /// Same result as [`MorseCodec::encode`]
+    pub fn encode(&mut self, text: &str) -> String {
+        let codec = &self.codec;
+        cached(&mut self.encoded, &mut self.stats, text, |text| {
+            codec.encode(text)
+        })
+    }
+
+    /// Decodes Morse code, reusing a cached result when available.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode
+    ///
+    /// # Returns
+    ///
+    /// Same result as [`MorseCodec::decode`]
+    pub fn decode(&mut self, morse: &str) -> String {
+        let codec = &self.codec;
+        cached(&mut self.decoded, &mut self.stats, morse, |morse| {
+            codec.decode(morse)
+        })
+    }
+
+    /// Returns the hit and miss counts since creation or the last clear.
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Returns the maximum number of results kept per direction.
+    pub fn capacity(&self) -> usize {
+        self.encoded.capacity
+    }
+
+    /// Empties both caches and resets the statistics.
+    pub fn clear(&mut self) {
+        self.encoded.clear();
+        self.decoded.clear();
+        self.stats = CacheStats::default();
+    }
+
+    /// Returns the wrapped codec.
+    pub fn codec(&self) -> &MorseCodec {
+        &self.codec
+    }
+}
+
+/// Looks up `input` in `cache`, computing and storing the result on a miss.
+fn cached(
+    cache: &mut Lru,
+    stats: &mut CacheStats,
+    input: &str,
+    convert: impl FnOnce(&str) -> String,
+) -> String {
+    if let Some(output) = cache.get(input) {
+        stats.hits += 1;
+        return output;
+    }
+
+    stats.misses += 1;
+    let output = convert(input);
+    cache.insert(input.to_string(), output.clone());
+    output
+}
+
+/// Least-recently-used map from inputs to conversion results.
+struct Lru {
+    capacity: usize,
+    entries: BTreeMap<String, (String, u64)>,
+    // Keys by last use, oldest first.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Self {
+        Lru {
+            capacity,
+            entries: BTreeMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<String> {
+        let (value, used) = self.entries.get_mut(key)?;
+        let key = self.order.remove(used)?;
+        self.tick += 1;
+        *used = self.tick;
+        self.order.insert(self.tick, key);
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: String, value: String) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = self.order.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, (value, self.tick));
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
@@ -0,0 +1,45 @@
+//! Per-element amplitude variation imitating an operator's "fist".
+
+use crate::timing::{random_seed, splitmix64};
+
+/// Deterministic loudness pattern applied to the elements of each letter.
+///
+/// Every position within a letter (first element, second element, ...)
+/// gets its own fixed gain, so the same letter always sounds the same way,
+/// as it does from one operator, while different seeds sound like different
+/// operators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FistProfile {
+    depth: f64,
+    seed: u64,
+}
+
+impl FistProfile {
+    /// Creates a profile that softens elements by up to `depth` (clamped to
+    /// 0.0-0.5), or picks a random seed when none is given.
+    pub(crate) fn new(depth: f64, seed: Option<u64>) -> Self {
+        FistProfile {
+            depth: depth.clamp(0.0, 0.5),
+            seed: seed.unwrap_or_else(random_seed),
+        }
+    }
+
+    /// Returns the gain, between `1.0 - depth` and 1.0, of the element at
+    /// `position` within its letter.
+    pub(crate) fn gain(&self, position: usize) -> f64 {
+        let mut state = self.seed.wrapping_add(position as u64);
+        let unit = (splitmix64(&mut state) >> 11) as f64 / (1u64 << 53) as f64;
+        1.0 - self.depth * unit
+    }
+
+    /// Returns the gain of every element of a Morse string, in order.
+    pub(crate) fn gains<'a>(&'a self, morse: &'a str) -> impl Iterator<Item = f64> + 'a {
+        morse.split_whitespace().flat_map(move |token| {
+            token
+                .chars()
+                .filter(|&c| c == '.' || c == '-')
+                .enumerate()
+                .map(move |(position, _)| self.gain(position))
+        })
+    }
+}
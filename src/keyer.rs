@@ -0,0 +1,253 @@
+//! Paddle keyer emulation for straight and iambic keying.
+
+use crate::timing::{self, TimingError};
+use alloc::string::String;
+
+/// How a [`Keyer`] turns paddle contacts into elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyerMode {
+    /// Either paddle acts as a straight key, and each element's length
+    /// follows how long the contact is held.
+    #[default]
+    Straight,
+    /// Iambic mode A. Holding a paddle repeats its element and squeezing
+    /// both alternates dots and dashes, stopping once the paddles are
+    /// released.
+    IambicA,
+    /// Iambic mode B. Like mode A, but releasing a squeeze sends one more
+    /// alternate element.
+    IambicB,
+}
+
+/// The state of both paddle contacts from a point in time onward.
+///
+/// # Fields
+///
+/// * `time_ms` - When the contacts changed, in milliseconds
+/// * `dit` - true while the dot paddle is pressed
+/// * `dah` - true while the dash paddle is pressed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaddleEvent {
+    pub time_ms: f64,
+    pub dit: bool,
+    pub dah: bool,
+}
+
+/// Electronic keyer that turns paddle input into Morse code.
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::{Keyer, KeyerMode, PaddleEvent};
+///
+/// // Squeeze both paddles for 300 ms, dot paddle first.
+/// let squeeze = [
+///     PaddleEvent { time_ms: 0.0, dit: true, dah: true },
+///     PaddleEvent { time_ms: 300.0, dit: false, dah: false },
+/// ];
+///
+/// let mode_a = Keyer::new(KeyerMode::IambicA, 20.0).unwrap();
+/// assert_eq!(mode_a.generate_iambic(&squeeze), ".-");
+///
+/// let mode_b = Keyer::new(KeyerMode::IambicB, 20.0).unwrap();
+/// assert_eq!(mode_b.generate_iambic(&squeeze), ".-.");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keyer {
+    mode: KeyerMode,
+    dot_duration: f64,
+}
+
+impl Keyer {
+    /// Creates a keyer sending at the given speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - How paddle contacts are turned into elements
+    /// * `wpm` - Words per minute, 1-60 (dot_duration_ms = 1200 / WPM)
+    ///
+    /// # Returns
+    ///
+    /// The keyer, or a `TimingError` if the speed is out of range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{Keyer, KeyerMode};
+    ///
+    /// assert!(Keyer::new(KeyerMode::IambicA, 25.0).is_ok());
+    /// assert!(Keyer::new(KeyerMode::IambicA, -20.0).is_err());
+    /// assert!(Keyer::new(KeyerMode::IambicB, f64::NAN).is_err());
+    /// ```
+    pub fn new(mode: KeyerMode, wpm: f64) -> Result<Self, TimingError> {
+        timing::check_wpm(wpm)?;
+
+        Ok(Keyer {
+            mode,
+            dot_duration: 1200.0 / wpm,
+        })
+    }
+
+    /// Returns the keying mode.
+    pub fn mode(&self) -> KeyerMode {
+        self.mode
+    }
+
+    /// Generates Morse code from a sequence of paddle contacts.
+    ///
+    /// Silences of two units or more end a letter and five units or more
+    /// end a word. Input past the last event is treated as released.
+    ///
+    /// # Arguments
+    ///
+    /// * `paddle_events` - Paddle states in time order
+    ///
+    /// # Returns
+    ///
+    /// Morse code string with `" "` between letters and `" / "` between
+    /// words
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{Keyer, KeyerMode, PaddleEvent};
+    ///
+    /// let keyer = Keyer::new(KeyerMode::Straight, 20.0).unwrap();
+    /// let events = [
+    ///     PaddleEvent { time_ms: 0.0, dit: true, dah: false },
+    ///     PaddleEvent { time_ms: 60.0, dit: false, dah: false },
+    ///     PaddleEvent { time_ms: 120.0, dit: true, dah: false },
+    ///     PaddleEvent { time_ms: 300.0, dit: false, dah: false },
+    ///     PaddleEvent { time_ms: 500.0, dit: true, dah: false },
+    ///     PaddleEvent { time_ms: 560.0, dit: false, dah: false },
+    /// ];
+    /// assert_eq!(keyer.generate_iambic(&events), ".- .");
+    /// ```
+    pub fn generate_iambic(&self, paddle_events: &[PaddleEvent]) -> String {
+        match self.mode {
+            KeyerMode::Straight => self.straight(paddle_events),
+            KeyerMode::IambicA | KeyerMode::IambicB => self.iambic(paddle_events),
+        }
+    }
+
+    /// Times each contact closure like a straight key.
+    fn straight(&self, events: &[PaddleEvent]) -> String {
+        let mut morse = String::new();
+        let mut down_since = None;
+        let mut up_since = None;
+
+        for event in events {
+            match (event.dit || event.dah, down_since) {
+                (true, None) => {
+                    if let Some(up) = up_since {
+                        self.push_gap(&mut morse, event.time_ms - up);
+                    }
+                    down_since = Some(event.time_ms);
+                }
+                (false, Some(down)) => {
+                    let units = (event.time_ms - down) / self.dot_duration;
+                    morse.push(if units < 2.0 { '.' } else { '-' });
+                    down_since = None;
+                    up_since = Some(event.time_ms);
+                }
+                _ => {}
+            }
+        }
+
+        morse
+    }
+
+    /// Clocks out self-completing elements like an iambic keyer.
+    fn iambic(&self, events: &[PaddleEvent]) -> String {
+        let mut morse = String::new();
+        let Some(last_ms) = events.last().map(|event| event.time_ms) else {
+            return morse;
+        };
+        let Some(mut time) = next_press(events, f64::NEG_INFINITY) else {
+            return morse;
+        };
+        let mut last = None;
+        let mut extra = None;
+
+        loop {
+            let element = match extra.take() {
+                Some(element) => element,
+                None if time > last_ms => break,
+                None => match state_at(events, time) {
+                    (true, true) if last == Some('.') => '-',
+                    (true, _) => '.',
+                    (false, true) => '-',
+                    (false, false) => {
+                        let Some(next) = next_press(events, time) else {
+                            break;
+                        };
+                        // The element gap already elapsed before `time`.
+                        self.push_gap(&mut morse, next - time + self.dot_duration);
+                        time = next;
+                        last = None;
+                        continue;
+                    }
+                },
+            };
+
+            let units = if element == '.' { 1.0 } else { 3.0 };
+            let end = time + (units + 1.0) * self.dot_duration;
+            // Time must move forward, or the loop would never reach the end.
+            if end <= time {
+                break;
+            }
+
+            if self.mode == KeyerMode::IambicB
+                && squeezed_during(events, time, end)
+                && state_at(events, end) == (false, false)
+            {
+                extra = Some(if element == '.' { '-' } else { '.' });
+            }
+
+            morse.push(element);
+            last = Some(element);
+            time = end;
+        }
+
+        morse
+    }
+
+    /// Appends a letter or word break for a silence, if it is long enough.
+    fn push_gap(&self, morse: &mut String, silence_ms: f64) {
+        let units = silence_ms / self.dot_duration;
+        if units >= 5.0 {
+            morse.push_str(" / ");
+        } else if units >= 2.0 {
+            morse.push(' ');
+        }
+    }
+}
+
+/// Returns the `(dit, dah)` contacts in effect at `time_ms`.
+fn state_at(events: &[PaddleEvent], time_ms: f64) -> (bool, bool) {
+    events
+        .iter()
+        .rev()
+        .find(|event| event.time_ms <= time_ms)
+        .map_or((false, false), |event| (event.dit, event.dah))
+}
+
+/// Returns when a paddle is next pressed at or after `time_ms`.
+fn next_press(events: &[PaddleEvent], time_ms: f64) -> Option<f64> {
+    if state_at(events, time_ms) != (false, false) {
+        return Some(time_ms);
+    }
+
+    events
+        .iter()
+        .find(|event| event.time_ms > time_ms && (event.dit || event.dah))
+        .map(|event| event.time_ms)
+}
+
+/// Returns whether both paddles were pressed at any point in a span.
+fn squeezed_during(events: &[PaddleEvent], start_ms: f64, end_ms: f64) -> bool {
+    state_at(events, start_ms) == (true, true)
+        || events.iter().any(|event| {
+            event.time_ms > start_ms && event.time_ms < end_ms && event.dit && event.dah
+        })
+}
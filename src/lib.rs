@@ -9,8 +9,20 @@
 //!
 //! - **Fast Encoding/Decoding**: Convert text to Morse code and back in <1ms
 //! - **Audio Playback**: Real-time Morse code audio synthesis
+//! - **WAV Export**: Render Morse code to WAV files without a browser
 //! - **WebAssembly Support**: Compile to WASM for browser usage
-//! - **Full Character Set**: Supports letters, numbers, and punctuation
+//! - **Full Character Set**: Supports letters, accented letters, numbers, and punctuation
+//! - **Multiple Alphabets**: International, Wabun (Japanese), American, Greek, and Cyrillic Morse
+//!
+//! ## Cargo Features
+//!
+//! - `std` (default): Standard library support, including WAV export,
+//!   timestamps, and the shared codec behind [`encode`] and [`decode`]
+//! - `wasm` (default): WebAssembly bindings and Web Audio playback
+//!   (`MorseWave`, `AudioPlayer`); implies `std`
+//!
+//! With default features disabled the codec is `no_std` and only needs
+//! `alloc`, so the encode/decode tables can be reused in embedded firmware.
 //!
 //! ## Example
 //!
@@ -25,10 +37,61 @@
 //! assert_eq!(decoded, "HELLO");
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "wasm")]
+use fist::FistProfile;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+#[cfg(feature = "wasm")]
+use std::cell::{Cell, RefCell};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(feature = "wasm")]
+use std::collections::VecDeque;
+#[cfg(feature = "wasm")]
+use std::rc::Rc;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+#[cfg(all(feature = "std", not(all(target_arch = "wasm32", feature = "wasm"))))]
+use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
-use web_sys::AudioContext;
+#[cfg(feature = "wasm")]
+use web_sys::{AudioContext, GainNode, OscillatorNode, OscillatorType};
+
+mod abbreviation;
+mod alphabet;
+#[cfg(feature = "std")]
+mod base64;
+mod cache;
+#[cfg(feature = "std")]
+mod fist;
+mod keyer;
+mod phonetic;
+mod prosign;
+mod rst;
+mod stream;
+mod timing;
+#[cfg(feature = "std")]
+mod wav;
+
+pub use alphabet::Alphabet;
+pub use cache::{CacheStats, CachingCodec};
+pub use keyer::{Keyer, KeyerMode, PaddleEvent};
+pub use prosign::Prosign;
+pub use rst::{rst, RstError};
+pub use stream::{MorseStreamDecoder, MorseStreamEncoder};
+pub use timing::{scale_timeline, MorseTiming, ScaleError, TimingError, ToneEvent};
+#[cfg(feature = "std")]
+pub use wav::{MorseWavEncoder, WavError};
 
 /// Represents a Morse code message with its text, encoded form, and timestamp.
 ///
@@ -37,17 +100,308 @@ use web_sys::AudioContext;
 /// * `text` - The original text message
 /// * `morse` - The Morse code representation
 /// * `timestamp` - Unix timestamp in milliseconds
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", wasm_bindgen(getter_with_clone))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MorseMessage {
     pub text: String,
     pub morse: String,
     pub timestamp: f64,
 }
 
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl MorseMessage {
+    /// Creates a message from its parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The original text message
+    /// * `morse` - The Morse code representation
+    /// * `timestamp` - Unix timestamp in milliseconds
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(text: String, morse: String, timestamp: f64) -> Self {
+        MorseMessage {
+            text,
+            morse,
+            timestamp,
+        }
+    }
+}
+
+/// Error returned when a compact message string cannot be read.
+///
+/// # Fields
+///
+/// * `message` - Description of what was wrong with the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid compact message: {}", self.message)
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+#[cfg(feature = "std")]
+impl MorseMessage {
+    /// Packs the message into a compact string for sharing in a URL.
+    ///
+    /// The JSON form of the message is encoded as URL-safe base64 without
+    /// padding, so the result can go into a query string or fragment as is.
+    ///
+    /// # Returns
+    ///
+    /// Compact string accepted by [`from_compact`](Self::from_compact)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseMessage;
+    ///
+    /// let message = MorseMessage::new("SOS".into(), "... --- ...".into(), 1700000000000.0);
+    /// let compact = message.to_compact();
+    /// assert!(compact.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+    /// assert_eq!(MorseMessage::from_compact(&compact).unwrap(), message);
+    ///
+    /// assert!(MorseMessage::from_compact("not base64!").is_err());
+    /// ```
+    pub fn to_compact(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        base64::encode(&json)
+    }
+
+    /// Unpacks a message produced by [`to_compact`](Self::to_compact).
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The compact string
+    ///
+    /// # Returns
+    ///
+    /// The message, or a `ParseError` if `s` is not valid base64 or does not
+    /// hold a message
+    pub fn from_compact(s: &str) -> Result<MorseMessage, ParseError> {
+        let json = base64::decode(s).map_err(|position| ParseError {
+            message: format!("invalid base64 at byte {}", position),
+        })?;
+        serde_json::from_slice(&json).map_err(|err| ParseError {
+            message: err.to_string(),
+        })
+    }
+}
+
+/// Error returned when a Morse sequence cannot be decoded.
+///
+/// # Fields
+///
+/// * `token` - The Morse token that has no known mapping
+/// * `position` - Zero-based index of the token within the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    pub token: String,
+    pub position: usize,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid Morse sequence '{}' at position {}",
+            self.token, self.position
+        )
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Error returned when a custom mapping conflicts with an existing one.
+///
+/// # Fields
+///
+/// * `code` - The Morse code that was requested
+/// * `existing` - The character the code is already assigned to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappingError {
+    pub code: String,
+    pub existing: char,
+}
+
+impl fmt::Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Morse code '{}' is already assigned to '{}'",
+            self.code, self.existing
+        )
+    }
+}
+
+impl core::error::Error for MappingError {}
+
+/// Error returned when text contains characters with no Morse mapping.
+///
+/// # Fields
+///
+/// * `unsupported` - Byte offset and character of every unsupported character
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodeError {
+    pub unsupported: Vec<(usize, char)>,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsupported characters:")?;
+        for (position, ch) in &self.unsupported {
+            write!(f, " '{}' at {}", ch, position)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for EncodeError {}
+
+/// Error returned when a word or symbol separator cannot be used.
+///
+/// # Fields
+///
+/// * `separator` - The rejected separator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeparatorError {
+    pub separator: String,
+}
+
+impl fmt::Display for SeparatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid separator '{}'", self.separator)
+    }
+}
+
+impl core::error::Error for SeparatorError {}
+
+/// Element and character counts for an encoded message.
+///
+/// # Fields
+///
+/// * `dots` - Number of dots
+/// * `dashes` - Number of dashes
+/// * `elements` - Total dots and dashes
+/// * `characters` - Number of encoded characters, excluding word separators
+/// * `words` - Number of words
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MorseStats {
+    pub dots: usize,
+    pub dashes: usize,
+    pub elements: usize,
+    pub characters: usize,
+    pub words: usize,
+}
+
+impl MorseStats {
+    /// Estimates the fraction of transmission time the key is held down.
+    ///
+    /// Uses standard timing: dots are 1 unit, dashes 3, with 1-unit gaps
+    /// between elements, 3-unit gaps between letters, and 7-unit gaps
+    /// between words.
+    ///
+    /// # Returns
+    ///
+    /// Key-down fraction between 0.0 and 1.0 (0.0 for an empty message)
+    pub fn key_down_fraction(&self) -> f64 {
+        let key_down = self.dots + 3 * self.dashes;
+        let element_gaps = self.elements.saturating_sub(self.characters);
+        let letter_gaps = 3 * self.characters.saturating_sub(self.words);
+        let word_gaps = 7 * self.words.saturating_sub(1);
+        let total = key_down + element_gaps + letter_gaps + word_gaps;
+
+        if total == 0 {
+            0.0
+        } else {
+            key_down as f64 / total as f64
+        }
+    }
+}
+
+/// What [`MorseCodec::encode_with_policy`] does with characters that have no
+/// Morse mapping.
+///
+/// With the International alphabet every character of `"Hi!é"` is
+/// supported, so all policies give `".... .. -.-.-- ..-.."`. The American
+/// alphabet has no `É`, and the variants below show the output for `"Hi!é"`
+/// there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownPolicy {
+    /// Drop the character, as [`MorseCodec::encode`] does:
+    /// `".... .. ---."`.
+    #[default]
+    Skip,
+    /// Encode the given character in its place; with `Replace('?')`:
+    /// `".... .. ---. -..-."`. Nothing is emitted if the replacement is
+    /// unsupported too.
+    Replace(char),
+    /// Pass the character through as its own token: `".... .. ---. é"`.
+    Literal,
+    /// Fail with an [`EncodeError`] listing every unsupported character:
+    /// `[(3, 'é')]`.
+    Error,
+}
+
+/// One note of the rhythm produced by [`MorseCodec::to_rhythm`].
+///
+/// # Fields
+///
+/// * `pitch_on` - true for a sounding note, false for a rest
+/// * `beats` - Length of the note, where one beat is one dot
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Note {
+    pub pitch_on: bool,
+    pub beats: f64,
+}
+
+/// One item of structured Morse code produced by
+/// [`MorseCodec::encode_tokens`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MorseToken {
+    /// The Morse code of one character, e.g. `".-"`.
+    Letter(String),
+    /// A break between two words.
+    WordBreak,
+}
+
+/// One step of an element-wise alignment produced by [`MorseCodec::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    /// The element was sent as expected.
+    Match(char),
+    /// An element was sent that was not expected.
+    Insert(char),
+    /// An expected element was left out.
+    Delete(char),
+    /// A different element was sent in place of the expected one.
+    Substitute { expected: char, actual: char },
+}
+
+/// A single decoded token produced by [`MorseCodec::decode_events`].
+///
+/// # Fields
+///
+/// * `token` - The Morse token as it appears in the input
+/// * `decoded` - The decoded character, or `None` if the token is unknown
+/// * `start` - Byte offset of the token within the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeEvent<'a> {
+    pub token: &'a str,
+    pub decoded: Option<char>,
+    pub start: usize,
+}
+
 /// Core Morse code encoder and decoder.
 ///
 /// Provides bidirectional conversion between text and Morse code using
-/// International Morse Code standard (ITU-R M.1677-1).
+/// International Morse Code standard (ITU-R M.1677-1) by default, or any
+/// other [`Alphabet`] via [`MorseCodec::with_alphabet`].
 ///
 /// # Example
 ///
@@ -59,8 +413,16 @@ pub struct MorseMessage {
 /// assert_eq!(morse, "... --- ...");
 /// ```
 pub struct MorseCodec {
-    encode_map: HashMap<char, &'static str>,
-    decode_map: HashMap<&'static str, char>,
+    alphabet: Alphabet,
+    encode_map: BTreeMap<char, String>,
+    decode_map: BTreeMap<String, char>,
+    // Mirrors the dot/dash codes of `decode_map`, indexed by `code_index`,
+    // so decoding avoids string comparisons for the common case.
+    decode_index: Vec<Option<char>>,
+    word_separator: String,
+    morse_threshold: f64,
+    cut_numbers: bool,
+    symbol_separators: (String, String),
 }
 
 impl Default for MorseCodec {
@@ -79,215 +441,3645 @@ impl MorseCodec {
     /// ```rust
     /// use morsewave::MorseCodec;
     /// let codec = MorseCodec::new();
+    ///
+    /// for letter in ["É", "Ñ", "Ü"] {
+    ///     assert_eq!(codec.decode(&codec.encode(letter)), letter);
+    /// }
+    /// assert_eq!(codec.encode("ñ"), "--.--");
     /// ```
     pub fn new() -> Self {
-        let pairs = vec![
-            ('A', ".-"),
-            ('B', "-..."),
-            ('C', "-.-."),
-            ('D', "-.."),
-            ('E', "."),
-            ('F', "..-."),
-            ('G', "--."),
-            ('H', "...."),
-            ('I', ".."),
-            ('J', ".---"),
-            ('K', "-.-"),
-            ('L', ".-.."),
-            ('M', "--"),
-            ('N', "-."),
-            ('O', "---"),
-            ('P', ".--."),
-            ('Q', "--.-"),
-            ('R', ".-."),
-            ('S', "..."),
-            ('T', "-"),
-            ('U', "..-"),
-            ('V', "...-"),
-            ('W', ".--"),
-            ('X', "-..-"),
-            ('Y', "-.--"),
-            ('Z', "--.."),
-            ('0', "-----"),
-            ('1', ".----"),
-            ('2', "..---"),
-            ('3', "...--"),
-            ('4', "....-"),
-            ('5', "....."),
-            ('6', "-...."),
-            ('7', "--..."),
-            ('8', "---.."),
-            ('9', "----."),
-            ('.', ".-.-.-"),
-            (',', "--..--"),
-            ('?', "..--.."),
-            ('!', "-.-.--"),
-            ('/', "-..-."),
-            ('(', "-.--."),
-            (')', "-.--.-"),
-            ('&', ".-..."),
-            (':', "---..."),
-            (';', "-.-.-."),
-            ('=', "-...-"),
-            ('+', ".-.-."),
-            ('-', "-....-"),
-            ('_', "..--.-"),
-            ('"', ".-..-."),
-            ('$', "...-..-"),
-            ('@', ".--.-."),
-            (' ', "/"),
-        ];
-
-        let mut encode_map = HashMap::new();
-        let mut decode_map = HashMap::new();
-
-        for (ch, morse) in pairs.iter() {
-            encode_map.insert(*ch, *morse);
-            decode_map.insert(*morse, *ch);
+        Self::with_alphabet(Alphabet::International)
+    }
+
+    /// Creates a new MorseCodec using the given alphabet's mappings.
+    ///
+    /// # Arguments
+    ///
+    /// * `alphabet` - The Morse alphabet to encode and decode with
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{Alphabet, MorseCodec};
+    ///
+    /// let codec = MorseCodec::with_alphabet(Alphabet::Wabun);
+    /// assert_eq!(codec.encode("ガ"), ".-.. ..");
+    /// assert_eq!(codec.decode(".-.. .."), "ガ");
+    ///
+    /// let american = MorseCodec::with_alphabet(Alphabet::American);
+    /// assert_eq!(american.encode("C"), ".. .");
+    /// assert_ne!(american.encode("C"), MorseCodec::new().encode("C"));
+    /// assert_eq!(american.decode(".. . -.-"), "CK");
+    /// ```
+    pub fn with_alphabet(alphabet: Alphabet) -> Self {
+        let mut encode_map = BTreeMap::new();
+        let mut decode_map = BTreeMap::new();
+
+        for (ch, morse) in alphabet.table().iter() {
+            encode_map.insert(*ch, morse.to_string());
+            decode_map.insert(morse.to_string(), *ch);
+        }
+
+        let mut decode_index = vec![None; 1 << (MAX_INDEXED_ELEMENTS + 1)];
+        for (code, &ch) in &decode_map {
+            if let Some(index) = code_index(code) {
+                decode_index[index] = Some(ch);
+            }
         }
 
         MorseCodec {
+            alphabet,
             encode_map,
             decode_map,
+            decode_index,
+            word_separator: "/".to_string(),
+            morse_threshold: 0.9,
+            cut_numbers: false,
+            symbol_separators: (" ".to_string(), " | ".to_string()),
         }
     }
 
-    /// Encodes text into Morse code.
+    /// Creates a codec from a custom character table.
     ///
-    /// Converts uppercase letters, numbers, and punctuation to Morse code.
-    /// Unknown characters are silently ignored.
+    /// Entries are taken as given, without the conflict check of
+    /// [`insert_mapping`](Self::insert_mapping). When several characters
+    /// share a code, decoding uses the last of them; use
+    /// [`find_collisions`](Self::find_collisions) to catch such mistakes.
+    /// Text is normalized as for the International alphabet, and the word
+    /// separator is `/` (a `' '` entry is ignored; see
+    /// [`set_word_separator`](Self::set_word_separator)).
     ///
     /// # Arguments
     ///
-    /// * `text` - The text to encode
-    ///
-    /// # Returns
-    ///
-    /// Morse code string with spaces between letters and '/' for word spaces
+    /// * `table` - `(char, morse)` pairs in order of precedence
     ///
     /// # Example
     ///
     /// ```rust
     /// use morsewave::MorseCodec;
-    /// let codec = MorseCodec::new();
-    /// assert_eq!(codec.encode("HELLO"), ".... . .-.. .-.. ---");
+    ///
+    /// let codec = MorseCodec::from_table(&[('E', "."), ('T', "-")]);
+    /// assert_eq!(codec.encode("TEA"), "- .");
+    /// assert_eq!(codec.decode("- . / ."), "TE E");
     /// ```
-    pub fn encode(&self, text: &str) -> String {
-        text.to_uppercase()
-            .chars()
-            .filter_map(|ch| self.encode_map.get(&ch))
-            .map(|s| s.to_string())
-            .collect::<Vec<_>>()
-            .join(" ")
+    pub fn from_table(table: &[(char, &str)]) -> Self {
+        let mut codec = Self::new();
+        codec.encode_map.clear();
+        codec.decode_map.clear();
+        codec.decode_index.fill(None);
+
+        codec.encode_map.insert(' ', "/".to_string());
+        codec.map_code("/", ' ');
+
+        for &(ch, code) in table.iter().filter(|&&(ch, _)| ch != ' ') {
+            let ch = to_upper(ch);
+            codec.encode_map.insert(ch, code.to_string());
+            codec.map_code(code, ch);
+        }
+
+        codec
     }
 
-    /// Decodes Morse code back into text.
+    /// Adds or overrides a character mapping.
     ///
-    /// Converts Morse code sequences into their corresponding characters.
-    /// Invalid Morse sequences are silently ignored.
+    /// Letters are stored uppercased, matching how input is normalized
+    /// before encoding. Overriding a character releases its previous code.
+    /// Mapping the space character changes the word separator, under the
+    /// same rules as [`set_word_separator`](Self::set_word_separator).
     ///
     /// # Arguments
     ///
-    /// * `morse` - The Morse code to decode (space-separated)
+    /// * `ch` - The character to map
+    /// * `code` - The Morse code to assign to it
     ///
     /// # Returns
     ///
-    /// Decoded text string
+    /// Ok on success, or a `MappingError` if `code` already belongs to a
+    /// different character (reported as `' '` for a word separator that is
+    /// not allowed)
     ///
     /// # Example
     ///
     /// ```rust
     /// use morsewave::MorseCodec;
-    /// let codec = MorseCodec::new();
-    /// assert_eq!(codec.decode("... --- ..."), "SOS");
+    /// let mut codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.encode("#"), "");
+    /// codec.insert_mapping('#', "...--.").unwrap();
+    /// assert_eq!(codec.encode("#"), "...--.");
+    /// assert_eq!(codec.decode("...--."), "#");
+    ///
+    /// let err = codec.insert_mapping('#', "...").unwrap_err();
+    /// assert_eq!(err.existing, 'S');
+    ///
+    /// let err = codec.insert_mapping(' ', ".-.-.-.-").unwrap_err();
+    /// assert_eq!(err.existing, ' ');
+    /// assert_eq!(codec.encode("HI MI"), ".... .. / -- ..");
     /// ```
-    pub fn decode(&self, morse: &str) -> String {
-        morse
-            .split(' ')
-            .filter_map(|code| self.decode_map.get(code))
-            .collect()
-    }
-}
+    pub fn insert_mapping(&mut self, ch: char, code: &str) -> Result<(), MappingError> {
+        let ch = to_upper(ch);
 
-/// WebAssembly-compatible Morse code interface.
-///
-/// Provides WASM bindings for encoding, decoding, and validating Morse code
-/// in browser environments.
-///
-/// # Example
-///
-/// ```javascript
-/// import init, { MorseWave } from './pkg/morsewave.js';
-///
-/// await init();
-/// const morse = new MorseWave();
-/// console.log(morse.text_to_morse("HELLO"));
-/// ```
-#[wasm_bindgen]
-pub struct MorseWave {
-    codec: MorseCodec,
-}
+        if ch == ' ' {
+            return self.set_word_separator(code).map_err(|_| MappingError {
+                code: code.to_string(),
+                existing: self.decode_map.get(code).copied().unwrap_or(' '),
+            });
+        }
 
-impl Default for MorseWave {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+        if let Some(&existing) = self.decode_map.get(code) {
+            if existing != ch {
+                return Err(MappingError {
+                    code: code.to_string(),
+                    existing,
+                });
+            }
+        }
 
-#[wasm_bindgen]
-impl MorseWave {
-    /// Creates a new MorseWave instance.
-    ///
-    /// Initializes panic hook for better error messages in browser console.
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> Self {
-        console_error_panic_hook::set_once();
-        MorseWave {
-            codec: MorseCodec::new(),
+        if let Some(previous) = self.encode_map.insert(ch, code.to_string()) {
+            self.unmap_code(&previous);
         }
+        self.map_code(code, ch);
+
+        Ok(())
     }
 
-    /// Converts text to Morse code.
+    /// Changes the symbol used between words.
+    ///
+    /// The separator is used for the space character when encoding and
+    /// recognized as a word break when decoding. It must be non-empty,
+    /// contain no dots, dashes, or whitespace, and not already be the code
+    /// of another character. Whitespace-based word gaps are handled by
+    /// [`decode_flexible`](Self::decode_flexible) instead.
     ///
     /// # Arguments
     ///
-    /// * `text` - Text to encode
+    /// * `separator` - The new word separator (`/` by default)
     ///
     /// # Returns
     ///
-    /// Morse code string
-    pub fn text_to_morse(&self, text: &str) -> String {
-        self.codec.encode(text)
+    /// Ok on success, or a `SeparatorError` if the separator is not allowed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let mut codec = MorseCodec::new();
+    ///
+    /// codec.set_word_separator("|").unwrap();
+    /// assert_eq!(codec.encode("HI MI"), ".... .. | -- ..");
+    /// assert_eq!(codec.decode(".... .. | -- .."), "HI MI");
+    ///
+    /// assert!(codec.set_word_separator(".-").is_err());
+    /// assert!(codec.set_word_separator("-..-.").is_err());
+    /// ```
+    pub fn set_word_separator(&mut self, separator: &str) -> Result<(), SeparatorError> {
+        let malformed = separator.is_empty()
+            || separator
+                .chars()
+                .any(|c| c == '.' || c == '-' || c.is_whitespace());
+        let taken = self.decode_map.get(separator).is_some_and(|&ch| ch != ' ');
+
+        if malformed || taken {
+            return Err(SeparatorError {
+                separator: separator.to_string(),
+            });
+        }
+
+        let previous = core::mem::take(&mut self.word_separator);
+        self.unmap_code(&previous);
+        self.map_code(separator, ' ');
+        self.encode_map.insert(' ', separator.to_string());
+        self.word_separator = separator.to_string();
+
+        Ok(())
     }
 
-    /// Converts Morse code to text.
+    /// Returns the symbol used between words.
+    pub fn word_separator(&self) -> &str {
+        &self.word_separator
+    }
+
+    /// Changes the separators used by [`encode_symbols`](Self::encode_symbols)
+    /// and [`decode_symbols`](Self::decode_symbols).
+    ///
+    /// Both separators must be non-empty, contain no `●` or `▬`, and differ.
+    /// Words are split before letters, so the word separator may contain the
+    /// letter separator but not the other way round.
     ///
     /// # Arguments
     ///
-    /// * `morse` - Morse code to decode
+    /// * `letter_sep` - Placed between letters (`" "` by default)
+    /// * `word_sep` - Placed between words (`" | "` by default)
     ///
     /// # Returns
     ///
-    /// Decoded text string
-    pub fn morse_to_text(&self, morse: &str) -> String {
-        self.codec.decode(morse)
+    /// Ok on success, or a `SeparatorError` naming the rejected separator
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let mut codec = MorseCodec::new();
+    ///
+    /// codec.set_symbol_separators("  ", " // ").unwrap();
+    /// assert_eq!(codec.encode_symbols("IT IS"), "●●  ▬ // ●●  ●●●");
+    ///
+    /// assert!(codec.set_symbol_separators("", "|").is_err());
+    /// assert!(codec.set_symbol_separators("||", "|").is_err());
+    /// ```
+    pub fn set_symbol_separators(
+        &mut self,
+        letter_sep: &str,
+        word_sep: &str,
+    ) -> Result<(), SeparatorError> {
+        let malformed = |sep: &str| sep.is_empty() || sep.contains(['●', '▬']);
+        let rejected = if malformed(letter_sep) || letter_sep.contains(word_sep) {
+            Some(letter_sep)
+        } else if malformed(word_sep) {
+            Some(word_sep)
+        } else {
+            None
+        };
+
+        if let Some(separator) = rejected {
+            return Err(SeparatorError {
+                separator: separator.to_string(),
+            });
+        }
+
+        self.symbol_separators = (letter_sep.to_string(), word_sep.to_string());
+        Ok(())
     }
 
-    /// Validates Morse code syntax.
+    /// Enables or disables cut numbers.
     ///
-    /// Checks if input contains only valid Morse characters (., -, /, space).
+    /// Contest and traffic operators shorten digits by sending them as
+    /// letters with similar codes. When enabled, digits are encoded using
+    /// the standard cut-number table:
+    ///
+    /// | Digit | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 0 |
+    /// |-------|---|---|---|---|---|---|---|---|---|---|
+    /// | Sent  | A | U | V | 4 | E | 6 | B | D | N | T |
+    ///
+    /// 4 and 6 have no common abbreviation and are sent in full. Decoding is
+    /// unaffected, so cut numbers decode as letters.
     ///
     /// # Arguments
     ///
-    /// * `morse` - Morse code to validate
+    /// * `enabled` - true to send digits as cut numbers
     ///
-    /// # Returns
+    /// # Example
     ///
-    /// true if valid, false otherwise
-    pub fn validate_morse(&self, morse: &str) -> bool {
-        morse
-            .split_whitespace()
-            .all(|code| code.chars().all(|c| c == '.' || c == '-' || c == '/'))
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let mut codec = MorseCodec::new();
+    ///
+    /// codec.set_cut_numbers(true);
+    /// assert_eq!(codec.encode("5NN 599"), ". -. -. / . -. -.");
+    /// assert_eq!(codec.encode("1904"), ".- -. - ....-");
+    /// ```
+    pub fn set_cut_numbers(&mut self, enabled: bool) {
+        self.cut_numbers = enabled;
+    }
+
+    /// Sets how much of an input must be Morse symbols to count as Morse.
+    ///
+    /// Used by [`looks_like_morse`](Self::looks_like_morse). Values are
+    /// clamped to 0.0-1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Minimum fraction of Morse symbols (0.9 default)
+    pub fn set_morse_threshold(&mut self, threshold: f64) {
+        self.morse_threshold = threshold.clamp(0.0, 1.0);
+    }
+
+    /// Guesses whether input is Morse code rather than plain text.
+    ///
+    /// Counts the non-whitespace characters that are dots, dashes, `/`, or
+    /// part of the word separator. The input is Morse when that fraction
+    /// reaches the threshold set with
+    /// [`set_morse_threshold`](Self::set_morse_threshold) and it contains
+    /// at least one dot or dash. Empty input is never Morse, while a lone
+    /// `-` is, since it is a valid code (T).
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Text or Morse code to classify
+    ///
+    /// # Returns
+    ///
+    /// true if the input looks like Morse code
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let mut codec = MorseCodec::new();
+    ///
+    /// assert!(codec.looks_like_morse("... --- ... / .... .."));
+    /// assert!(codec.looks_like_morse("-"));
+    /// assert!(!codec.looks_like_morse("HELLO - WORLD"));
+    /// assert!(!codec.looks_like_morse(""));
+    /// assert!(!codec.looks_like_morse(" / "));
+    ///
+    /// assert!(!codec.looks_like_morse("... --- xy"));
+    /// codec.set_morse_threshold(0.7);
+    /// assert!(codec.looks_like_morse("... --- xy"));
+    /// ```
+    pub fn looks_like_morse(&self, input: &str) -> bool {
+        let mut total = 0;
+        let mut symbols = 0;
+        let mut elements = 0;
+
+        for c in input.chars().filter(|c| !c.is_whitespace()) {
+            total += 1;
+            if c == '.' || c == '-' {
+                elements += 1;
+                symbols += 1;
+            } else if c == '/' || self.word_separator.contains(c) {
+                symbols += 1;
+            }
+        }
+
+        elements > 0 && symbols as f64 / total as f64 >= self.morse_threshold
+    }
+
+    /// Encodes text into Morse code.
+    ///
+    /// Converts uppercase letters, numbers, and punctuation to Morse code.
+    /// Unknown characters are silently ignored.
+    ///
+    /// Prosigns can be written in bracket notation, e.g. `<AR>`, and are sent
+    /// as a single run-together character. Brackets around anything other
+    /// than a known prosign are ignored and their contents are encoded as
+    /// ordinary letters.
+    ///
+    /// Accented letters encode the same whether they are typed precomposed
+    /// (NFC, `"é"`) or as a letter followed by a combining mark (NFD,
+    /// `"e\u{301}"`).
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    ///
+    /// # Returns
+    ///
+    /// Morse code string with spaces between letters and '/' for word spaces
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.encode("HELLO"), ".... . .-.. .-.. ---");
+    /// assert_eq!(codec.encode("73 <SK>"), "--... ...-- / ...-.-");
+    /// assert_eq!(codec.encode("<XY>"), "-..- -.--");
+    ///
+    /// assert_eq!(codec.encode("caf\u{e9}"), "-.-. .- ..-. ..-..");
+    /// assert_eq!(codec.encode("cafe\u{301}"), "-.-. .- ..-. ..-..");
+    /// assert!(codec.encode_strict("cafe\u{301}").is_ok());
+    /// ```
+    pub fn encode(&self, text: &str) -> String {
+        let mut out = String::new();
+        self.encode_into(text, &mut out);
+        out
+    }
+
+    /// Encodes text into a caller-owned buffer.
+    ///
+    /// The buffer is cleared first and then filled with the same output as
+    /// [`encode`](Self::encode). Reusing one buffer across calls avoids an
+    /// allocation per string in hot loops.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    /// * `out` - Buffer that receives the Morse code
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// let mut out = String::new();
+    ///
+    /// codec.encode_into("SOS", &mut out);
+    /// assert_eq!(out, "... --- ...");
+    /// codec.encode_into("HI", &mut out);
+    /// assert_eq!(out, ".... ..");
+    /// ```
+    pub fn encode_into(&self, text: &str, out: &mut String) {
+        out.clear();
+
+        for (i, (_, morse)) in self.encode_iter(text).enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            out.push_str(morse);
+        }
+    }
+
+    /// Encodes text, failing if any character has no Morse mapping.
+    ///
+    /// Unlike [`encode`](Self::encode), nothing is dropped silently: every
+    /// unsupported character is reported with its byte offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    ///
+    /// # Returns
+    ///
+    /// Morse code string, or an `EncodeError` listing unsupported characters
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.encode_strict("SOS").unwrap(), "... --- ...");
+    ///
+    /// let err = codec.encode_strict("HI#~").unwrap_err();
+    /// assert_eq!(err.unsupported, vec![(2, '#'), (3, '~')]);
+    /// ```
+    pub fn encode_strict(&self, text: &str) -> Result<String, EncodeError> {
+        let unsupported = self.find_unsupported(text);
+
+        if unsupported.is_empty() {
+            Ok(self.encode(text))
+        } else {
+            Err(EncodeError { unsupported })
+        }
+    }
+
+    /// Encodes text, handling unsupported characters as the policy says.
+    ///
+    /// Brings the dropping behavior of [`encode`](Self::encode) and the
+    /// failing behavior of [`encode_strict`](Self::encode_strict) together
+    /// with replacement and pass-through behind one parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    /// * `policy` - What to do with characters that have no Morse mapping
+    ///
+    /// # Returns
+    ///
+    /// Morse code string, or an `EncodeError` listing unsupported characters
+    /// under [`UnknownPolicy::Error`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{Alphabet, MorseCodec, UnknownPolicy};
+    /// let codec = MorseCodec::with_alphabet(Alphabet::American);
+    ///
+    /// let encode = |policy| codec.encode_with_policy("Hi!é", policy);
+    /// assert_eq!(encode(UnknownPolicy::Skip).unwrap(), ".... .. ---.");
+    /// assert_eq!(encode(UnknownPolicy::Replace('?')).unwrap(), ".... .. ---. -..-.");
+    /// assert_eq!(encode(UnknownPolicy::Literal).unwrap(), ".... .. ---. é");
+    /// assert_eq!(encode(UnknownPolicy::Error).unwrap_err().unsupported, vec![(3, 'é')]);
+    ///
+    /// let international = MorseCodec::new();
+    /// assert_eq!(
+    ///     international.encode_with_policy("Hi!é", UnknownPolicy::Error).unwrap(),
+    ///     ".... .. -.-.-- ..-.."
+    /// );
+    /// ```
+    pub fn encode_with_policy(
+        &self,
+        text: &str,
+        policy: UnknownPolicy,
+    ) -> Result<String, EncodeError> {
+        let replacement = match policy {
+            UnknownPolicy::Skip => return Ok(self.encode(text)),
+            UnknownPolicy::Error => return self.encode_strict(text),
+            UnknownPolicy::Replace(ch) => Some(ch),
+            UnknownPolicy::Literal => None,
+        };
+
+        Ok(self.encode_substituting(text, |ch| match replacement {
+            Some(replacement) => self.encode(replacement.encode_utf8(&mut [0; 4])),
+            None => ch.to_string(),
+        }))
+    }
+
+    /// Lists the characters of `text` that have no Morse mapping.
+    ///
+    /// Lets a UI warn which characters [`encode`](Self::encode) will drop
+    /// before the user commits. Letters are case-folded the same way as for
+    /// encoding, so `x` and `X` are reported once, as `X`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to check
+    ///
+    /// # Returns
+    ///
+    /// Distinct unsupported characters in order of first appearance
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{Alphabet, MorseCodec};
+    ///
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.unsupported_chars("café #1"), vec!['#']);
+    /// assert!(codec.unsupported_chars("SOS").is_empty());
+    ///
+    /// let american = MorseCodec::with_alphabet(Alphabet::American);
+    /// assert_eq!(american.unsupported_chars("café #1 É"), vec!['É', '#']);
+    /// ```
+    pub fn unsupported_chars(&self, text: &str) -> Vec<char> {
+        let mut chars = Vec::new();
+
+        for (_, ch) in self.find_unsupported(text) {
+            let ch = to_upper(ch);
+            if !chars.contains(&ch) {
+                chars.push(ch);
+            }
+        }
+
+        chars
+    }
+
+    /// Encodes text one word at a time.
+    ///
+    /// Splits the input on whitespace and encodes each word separately,
+    /// so the output lines up with the input words for word-synchronized
+    /// highlighting. Runs of whitespace never produce empty entries, and
+    /// punctuation stays with the word it is attached to.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    ///
+    /// # Returns
+    ///
+    /// One Morse string per input word, without word separators
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(
+    ///     codec.encode_words("HI,   SOS"),
+    ///     vec![".... .. --..--", "... --- ..."]
+    /// );
+    /// ```
+    pub fn encode_words(&self, text: &str) -> Vec<String> {
+        text.split_whitespace()
+            .map(|word| self.encode(word))
+            .collect()
+    }
+
+    /// Encodes text with custom separators at every level.
+    ///
+    /// Gives full control over how elements, letters, and words are
+    /// delimited, for formats that don't use the conventional single space
+    /// and `/`. Runs of whitespace in the input produce a single word
+    /// separator, and none is emitted at the start or end.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    /// * `element_sep` - Inserted between the dots and dashes of a letter
+    /// * `letter_sep` - Inserted between letters of a word
+    /// * `word_sep` - Inserted between words
+    ///
+    /// # Returns
+    ///
+    /// Morse code string using the given separators
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(
+    ///     codec.encode_elements("SOS  HI", ",", ";", "|"),
+    ///     ".,.,.;-,-,-;.,.,.|.,.,.,.;.,."
+    /// );
+    /// assert_eq!(codec.encode_elements("SOS", "", " ", " / "), codec.encode("SOS"));
+    /// ```
+    pub fn encode_elements(
+        &self,
+        text: &str,
+        element_sep: &str,
+        letter_sep: &str,
+        word_sep: &str,
+    ) -> String {
+        let mut out = String::new();
+        let mut started = false;
+        let mut word_break = false;
+
+        for (ch, morse) in self.encode_iter(text) {
+            if ch == ' ' {
+                word_break = true;
+                continue;
+            }
+
+            if started {
+                out.push_str(if word_break { word_sep } else { letter_sep });
+            }
+            started = true;
+            word_break = false;
+
+            for (i, element) in morse.chars().enumerate() {
+                if i > 0 {
+                    out.push_str(element_sep);
+                }
+                out.push(element);
+            }
+        }
+
+        out
+    }
+
+    /// Encodes text as visible glyphs, with `●` for dots and `▬` for dashes.
+    ///
+    /// Suited to print materials and accessibility contexts where solid
+    /// glyphs read better than ASCII dots and dashes. Letters and words are
+    /// separated as configured by
+    /// [`set_symbol_separators`](Self::set_symbol_separators).
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    ///
+    /// # Returns
+    ///
+    /// Glyph string, with `" "` between letters and `" | "` between words by
+    /// default
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.encode_symbols("SOS HI"), "●●● ▬▬▬ ●●● | ●●●● ●●");
+    /// ```
+    pub fn encode_symbols(&self, text: &str) -> String {
+        let (letter_sep, word_sep) = &self.symbol_separators;
+        let mut out = String::new();
+        let mut after_letter = false;
+
+        for token in self.encode_tokens(text) {
+            match token {
+                MorseToken::Letter(code) => {
+                    if after_letter {
+                        out.push_str(letter_sep);
+                    }
+                    out.extend(code.chars().map(|element| match element {
+                        '.' => '●',
+                        '-' => '▬',
+                        other => other,
+                    }));
+                    after_letter = true;
+                }
+                MorseToken::WordBreak => {
+                    out.push_str(word_sep);
+                    after_letter = false;
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Looks up the Morse code of a single character.
+    ///
+    /// Letters are matched case-insensitively and folded the same way as in
+    /// [`encode`](Self::encode), e.g. hiragana to katakana for Wabun.
+    /// Characters that need more than one code, such as voiced kana, have
+    /// no single code and return `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ch` - The character to look up
+    ///
+    /// # Returns
+    ///
+    /// The character's Morse code, or `None` if it has no mapping
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.char_to_morse('a'), Some(".-"));
+    /// assert_eq!(codec.char_to_morse('#'), None);
+    /// ```
+    pub fn char_to_morse(&self, ch: char) -> Option<&str> {
+        match self.alphabet.decompose(to_upper(ch)) {
+            (base, None) => self.code_for(base),
+            _ => None,
+        }
+    }
+
+    /// Looks up the character for a single Morse code.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Dots and dashes of one character, without spaces
+    ///
+    /// # Returns
+    ///
+    /// The decoded character, or `None` if the code is unknown
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.morse_to_char("-.-"), Some('K'));
+    /// assert_eq!(codec.morse_to_char("........."), None);
+    /// ```
+    pub fn morse_to_char(&self, code: &str) -> Option<char> {
+        self.lookup(code)
+    }
+
+    /// Lists every character mapping of this codec.
+    ///
+    /// Includes custom mappings added with
+    /// [`insert_mapping`](Self::insert_mapping), and the space character
+    /// mapped to the word separator.
+    ///
+    /// # Returns
+    ///
+    /// `(char, morse)` pairs sorted by character
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let table = codec.table();
+    /// assert_eq!(table[0], (' ', "/"));
+    /// assert!(table.contains(&('A', ".-")));
+    /// assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    /// ```
+    pub fn table(&self) -> Vec<(char, &str)> {
+        self.encode_map
+            .iter()
+            .map(|(&ch, morse)| (ch, morse.as_str()))
+            .collect()
+    }
+
+    /// Lists Morse codes that more than one character maps to.
+    ///
+    /// Such a code can only decode to one of its characters, so the others
+    /// silently stop round-tripping. [`insert_mapping`](Self::insert_mapping)
+    /// and [`set_word_separator`](Self::set_word_separator) refuse codes
+    /// that are already taken, so collisions come from tables passed to
+    /// [`from_table`](Self::from_table).
+    ///
+    /// # Returns
+    ///
+    /// `(morse, chars)` pairs sorted by code, with the characters sharing
+    /// each code in order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    ///
+    /// let codec = MorseCodec::from_table(&[('A', ".-"), ('B', "-..."), ('Ä', ".-")]);
+    /// assert_eq!(codec.find_collisions(), vec![(".-", vec!['A', 'Ä'])]);
+    /// assert_eq!(codec.decode(".- -..."), "ÄB");
+    ///
+    /// assert!(MorseCodec::new().find_collisions().is_empty());
+    /// ```
+    pub fn find_collisions(&self) -> Vec<(&str, Vec<char>)> {
+        let mut by_code: BTreeMap<&str, Vec<char>> = BTreeMap::new();
+        for (&ch, morse) in &self.encode_map {
+            by_code.entry(morse.as_str()).or_default().push(ch);
+        }
+
+        by_code
+            .into_iter()
+            .filter(|(_, chars)| chars.len() > 1)
+            .collect()
+    }
+
+    /// Encodes a prosign as its run-together element sequence.
+    ///
+    /// The letters of a prosign are sent without letter gaps, which is easy
+    /// to get wrong when concatenating codes by hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The prosign to encode
+    ///
+    /// # Returns
+    ///
+    /// Morse code without any spaces
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{MorseCodec, Prosign};
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.encode_prosign(Prosign::Sos), "...---...");
+    /// assert_eq!(codec.encode_prosign(Prosign::Ar), ".-.-.");
+    /// assert_eq!(codec.encode_prosign(Prosign::Sk), codec.encode("<SK>"));
+    /// ```
+    pub fn encode_prosign(&self, p: Prosign) -> String {
+        p.code().to_string()
+    }
+
+    /// Encodes an RST signal report.
+    ///
+    /// # Arguments
+    ///
+    /// * `readability` - Readability, 1 to 5
+    /// * `strength` - Signal strength, 1 to 9
+    /// * `tone` - Tone quality, 1 to 9
+    ///
+    /// # Returns
+    ///
+    /// Morse code of the three-digit report, or an `RstError` if a component
+    /// is out of range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.encode_rst(5, 9, 9).unwrap(), "..... ----. ----.");
+    /// assert!(codec.encode_rst(5, 10, 9).is_err());
+    /// ```
+    pub fn encode_rst(&self, readability: u8, strength: u8, tone: u8) -> Result<String, RstError> {
+        rst(readability, strength, tone).map(|report| self.encode(&report))
+    }
+
+    /// Encodes several parts as one transmission.
+    ///
+    /// Each part is encoded separately and the results are joined with the
+    /// word separator, which keeps field-based messages (callsign, report,
+    /// name) tidy. Parts that encode to nothing are skipped rather than
+    /// producing doubled separators.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` - Texts to encode in order
+    ///
+    /// # Returns
+    ///
+    /// Morse code string with `" / "` between parts
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(
+    ///     codec.encode_join(&["K1ABC", "", "5NN"]),
+    ///     "-.- .---- .- -... -.-. / ..... -. -."
+    /// );
+    /// ```
+    pub fn encode_join(&self, parts: &[&str]) -> String {
+        let separator = format!(" {} ", self.word_separator);
+        parts
+            .iter()
+            .map(|part| self.encode(part))
+            .filter(|morse| !morse.is_empty())
+            .collect::<Vec<_>>()
+            .join(&separator)
+    }
+
+    /// Encodes text as a list of letters and word breaks.
+    ///
+    /// Gives front-ends a structured model to render instead of parsing the
+    /// joined Morse string. Word breaks only appear between words, never at
+    /// the start or end, and runs of whitespace produce a single break.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    ///
+    /// # Returns
+    ///
+    /// Tokens in input order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{MorseCodec, MorseToken};
+    /// let codec = MorseCodec::new();
+    ///
+    /// let letter = |code: &str| MorseToken::Letter(code.to_string());
+    /// assert_eq!(
+    ///     codec.encode_tokens("HI BYE"),
+    ///     vec![
+    ///         letter("...."),
+    ///         letter(".."),
+    ///         MorseToken::WordBreak,
+    ///         letter("-..."),
+    ///         letter("-.--"),
+    ///         letter("."),
+    ///     ]
+    /// );
+    /// ```
+    pub fn encode_tokens(&self, text: &str) -> Vec<MorseToken> {
+        let mut tokens = Vec::new();
+        let mut word_break = false;
+
+        for (ch, morse) in self.encode_iter(text) {
+            if ch == ' ' {
+                word_break = true;
+                continue;
+            }
+
+            if word_break && !tokens.is_empty() {
+                tokens.push(MorseToken::WordBreak);
+            }
+            word_break = false;
+            tokens.push(MorseToken::Letter(morse.to_string()));
+        }
+
+        tokens
+    }
+
+    /// Returns the characters learned up to a Koch method lesson.
+    ///
+    /// Lesson 1 introduces K and M, and every later lesson adds one
+    /// character in the standard Koch order.
+    ///
+    /// # Arguments
+    ///
+    /// * `lesson` - Lesson number, starting at 1 (0 yields an empty set)
+    ///
+    /// # Returns
+    ///
+    /// Set of uppercase characters covered by the lesson
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// use std::collections::HashSet;
+    ///
+    /// assert_eq!(MorseCodec::koch_lesson(1), HashSet::from(['K', 'M']));
+    /// assert!(MorseCodec::koch_lesson(5).contains(&'S'));
+    /// assert!(MorseCodec::koch_lesson(0).is_empty());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn koch_lesson(lesson: usize) -> HashSet<char> {
+        let count = if lesson == 0 { 0 } else { lesson + 1 };
+        KOCH_ORDER.chars().take(count).collect()
+    }
+
+    /// Encodes only the characters in an allowed set.
+    ///
+    /// Characters are uppercased before the check, and word separators are
+    /// always kept. Combine with [`koch_lesson`](Self::koch_lesson) to
+    /// generate practice text limited to learned characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    /// * `allowed` - Uppercase characters that may be encoded
+    ///
+    /// # Returns
+    ///
+    /// Morse code for the allowed characters of `text`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let allowed = MorseCodec::koch_lesson(1);
+    /// assert_eq!(codec.encode_restricted("mask", &allowed), "-- -.-");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn encode_restricted(&self, text: &str, allowed: &HashSet<char>) -> String {
+        self.encode_iter(text)
+            .filter(|&(ch, _)| ch == ' ' || allowed.contains(&ch))
+            .map(|(_, morse)| morse)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Generates random practice words from an allowed set of characters.
+    ///
+    /// Each word is 3 to 6 characters long. The same seed always gives the
+    /// same drill, so lessons are reproducible. Characters the codec cannot
+    /// encode are ignored. Pairs with [`koch_lesson`](Self::koch_lesson) to
+    /// drill only learned characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowed` - Characters the words may use
+    /// * `word_count` - Number of words to generate
+    /// * `seed` - Seed for the pseudo-random generator
+    ///
+    /// # Returns
+    ///
+    /// Space-separated practice words, or an empty string if no allowed
+    /// character can be encoded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let allowed = MorseCodec::koch_lesson(3);
+    /// let drill = codec.random_drill(&allowed, 5, 42);
+    /// assert_eq!(drill, codec.random_drill(&allowed, 5, 42));
+    ///
+    /// let words: Vec<&str> = drill.split(' ').collect();
+    /// assert_eq!(words.len(), 5);
+    /// assert!(words.iter().all(|word| (3..=6).contains(&word.len())));
+    /// assert!(drill.chars().all(|ch| ch == ' ' || allowed.contains(&ch)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn random_drill(&self, allowed: &HashSet<char>, word_count: usize, seed: u64) -> String {
+        // Sorted so the drill does not depend on the set's iteration order.
+        let mut chars: Vec<char> = allowed
+            .iter()
+            .copied()
+            .filter(|&ch| ch != ' ' && self.code_for(ch).is_some())
+            .collect();
+        chars.sort_unstable();
+        if chars.is_empty() {
+            return String::new();
+        }
+
+        let mut state = seed;
+        let mut next = |bound: usize| (timing::splitmix64(&mut state) % bound as u64) as usize;
+
+        (0..word_count)
+            .map(|_| {
+                let len = 3 + next(4);
+                (0..len)
+                    .map(|_| chars[next(chars.len())])
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Lazily encodes text, yielding each character with its Morse code.
+    ///
+    /// Characters are uppercased (and decomposed for alphabets such as
+    /// Wabun) before lookup, so the yielded `char` is the symbol actually
+    /// transmitted. A letter followed by a combining accent is yielded as
+    /// the precomposed letter. Unknown characters are skipped. Nothing is
+    /// allocated for the output, which makes this suitable for streaming
+    /// large inputs.
+    ///
+    /// Known prosigns written in bracket notation such as `<AR>` are yielded
+    /// as a single pair whose `char` is `'<'`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    ///
+    /// # Returns
+    ///
+    /// Iterator of `(char, morse)` pairs in input order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let pairs: Vec<_> = codec.encode_iter("Hi").collect();
+    /// assert_eq!(pairs, vec![('H', "...."), ('I', "..")]);
+    ///
+    /// let joined = codec
+    ///     .encode_iter("HELLO WORLD")
+    ///     .map(|(_, morse)| morse)
+    ///     .collect::<Vec<_>>()
+    ///     .join(" ");
+    /// assert_eq!(joined, codec.encode("HELLO WORLD"));
+    /// ```
+    pub fn encode_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = (char, &'a str)> + 'a {
+        EncodeIter {
+            codec: self,
+            rest: text,
+            upper: None,
+            mark: None,
+        }
+    }
+
+    /// Decodes Morse code back into text.
+    ///
+    /// Converts Morse code sequences into their corresponding characters.
+    /// Invalid Morse sequences are silently ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.decode("... --- ..."), "SOS");
+    /// ```
+    pub fn decode(&self, morse: &str) -> String {
+        // Every character takes at least one element and a separator.
+        let mut text = String::with_capacity(morse.len() / 2 + 1);
+        for code in self.codes(morse) {
+            if let Some(ch) = self.lookup(code) {
+                text.push(ch);
+            }
+        }
+        self.alphabet.compose(text)
+    }
+
+    /// Decodes Morse code into one string per word.
+    ///
+    /// Keeps each word (callsign, report, name) as its own entry instead of
+    /// joining them, which makes logging exchanges straightforward. Empty
+    /// words, such as those between repeated separators, are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded words in order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(
+    ///     codec.decode_tokens(".-- .---- .- .-- / / -.. . / -.- .---- .- -... -.-"),
+    ///     vec!["W1AW", "DE", "K1ABK"]
+    /// );
+    /// assert!(codec.decode_tokens(" / ").is_empty());
+    /// ```
+    pub fn decode_tokens(&self, morse: &str) -> Vec<String> {
+        self.decode(morse)
+            .split_whitespace()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Decodes Morse code, reconstructing prosigns in bracket notation.
+    ///
+    /// Tokens matching a known prosign are rendered as `<NAME>` in
+    /// preference to any single character sharing the same code, so
+    /// `.-.-.` becomes `<AR>` rather than `+`.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string with prosigns in bracket notation
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.decode_prosigns("-.-. --.- .-.-."), "CQ<AR>");
+    /// ```
+    pub fn decode_prosigns(&self, morse: &str) -> String {
+        let mut text = String::with_capacity(morse.len());
+
+        for code in self.codes(morse) {
+            if let Some(name) = prosign::name(code) {
+                text.push('<');
+                text.push_str(name);
+                text.push('>');
+            } else if let Some(&ch) = self.decode_map.get(code) {
+                text.push(ch);
+            }
+        }
+
+        self.alphabet.compose(text)
+    }
+
+    /// Decodes a QSO transcript, annotating run-together prosigns.
+    ///
+    /// Tokens made up entirely of the prosigns used to hand over or close a
+    /// transmission are rendered in bracket notation, even when several are
+    /// sent without a letter gap between them or their code also belongs to
+    /// a character (`-.--.` is `<KN>` rather than `(`). All other tokens
+    /// decode as in [`decode`](Self::decode).
+    ///
+    /// Recognized prosigns: `<AR>`, `<AS>`, `<BK>`, `<CL>`, `<KN>`, `<SK>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string with QSO prosigns in bracket notation
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.decode_qso("-... -.- / -.--."), "BK <KN>");
+    /// assert_eq!(codec.decode_qso("- ..- / -...-.- "), "TU <BK>");
+    /// assert_eq!(codec.decode_qso("...-.--.-..-.."), "<SK><CL>");
+    /// ```
+    pub fn decode_qso(&self, morse: &str) -> String {
+        let mut text = String::with_capacity(morse.len());
+
+        for code in self.codes(morse) {
+            if let Some(prosigns) = prosign::split_qso(code).filter(|p| !p.is_empty()) {
+                for prosign in prosigns {
+                    text.push('<');
+                    text.push_str(prosign.name());
+                    text.push('>');
+                }
+            } else if let Some(&ch) = self.decode_map.get(code) {
+                text.push(ch);
+            }
+        }
+
+        self.alphabet.compose(text)
+    }
+
+    /// Decodes Morse code into lowercase text.
+    ///
+    /// Morse code has no notion of letter case, so the original casing of
+    /// encoded text cannot be recovered. This is a convenience for UIs that
+    /// prefer lowercase output over the uppercase returned by [`decode`](Self::decode).
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string in lowercase
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.decode_lower("... --- ..."), "sos");
+    /// ```
+    pub fn decode_lower(&self, morse: &str) -> String {
+        self.decode(morse).to_lowercase()
+    }
+
+    /// Decodes Morse code, correcting single-element errors.
+    ///
+    /// Unknown tokens are replaced by the character whose code is within
+    /// one insertion, deletion, or substitution of a dot or dash. When
+    /// several characters qualify, the most common English letter wins.
+    /// Tokens with no close match are decoded as `'\u{FFFD}'`.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string with corrections applied
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// // "-.-.-" has a stray dash; "-.-." (C) is the closest common letter.
+    /// assert_eq!(codec.decode_fuzzy("-.-.- --- .--. -.--"), "COPY");
+    /// assert_eq!(codec.decode_fuzzy("... -------- ..."), "S\u{FFFD}S");
+    /// ```
+    pub fn decode_fuzzy(&self, morse: &str) -> String {
+        let text = self
+            .codes(morse)
+            .filter(|code| !code.is_empty())
+            .map(|code| {
+                self.decode_map
+                    .get(code)
+                    .copied()
+                    .or_else(|| self.nearest_char(code))
+                    .unwrap_or('\u{FFFD}')
+            })
+            .collect();
+        self.alphabet.compose(text)
+    }
+
+    /// Decodes Morse code, recovering tokens that lost their letter gap.
+    ///
+    /// A common corruption is a missing space that merges two letters into
+    /// one unknown token. Each unknown token is split into two known codes
+    /// at whichever position gives the most common pair of letters. Tokens
+    /// that cannot be split that way are dropped, as in
+    /// [`decode`](Self::decode).
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string with merged tokens split apart
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.decode("...--- ..."), "S");
+    /// assert_eq!(codec.decode_recovering("...--- ..."), "SOS");
+    /// assert_eq!(codec.decode_recovering("............... ..."), "S");
+    /// ```
+    pub fn decode_recovering(&self, morse: &str) -> String {
+        let mut text = String::with_capacity(morse.len());
+
+        for code in self.codes(morse) {
+            if let Some(&ch) = self.decode_map.get(code) {
+                text.push(ch);
+                continue;
+            }
+
+            let best = (1..code.len())
+                .filter_map(|split| {
+                    let first = *self.decode_map.get(code.get(..split)?)?;
+                    let second = *self.decode_map.get(&code[split..])?;
+                    Some((first, second))
+                })
+                .filter(|&(first, second)| first != ' ' && second != ' ')
+                .min_by_key(|&(first, second)| frequency_rank(first) + frequency_rank(second));
+
+            if let Some((first, second)) = best {
+                text.push(first);
+                text.push(second);
+            }
+        }
+
+        self.alphabet.compose(text)
+    }
+
+    /// Decodes Morse code, marking unrecognized tokens with a placeholder.
+    ///
+    /// Every token yields exactly one character, so the output lines up
+    /// with the input tokens and decoding failures stay visible.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    /// * `placeholder` - Character emitted for each unrecognized token
+    ///   (`'#'` is a common choice)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string with placeholders for invalid tokens
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.decode_with_placeholder("... ........ ...", '#'), "S#S");
+    /// ```
+    pub fn decode_with_placeholder(&self, morse: &str, placeholder: char) -> String {
+        let text = self
+            .codes(morse)
+            .filter(|code| !code.is_empty())
+            .map(|code| self.decode_map.get(code).copied().unwrap_or(placeholder))
+            .collect();
+        self.alphabet.compose(text)
+    }
+
+    /// Decodes Morse code and scores how clean the input was.
+    ///
+    /// Invalid tokens are skipped in the output, as with
+    /// [`decode`](Self::decode), but lower the score.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text and the fraction of tokens that were valid (0.0-1.0).
+    /// Input with no tokens scores 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.decode_with_confidence("... --- ..."), ("SOS".to_string(), 1.0));
+    /// assert_eq!(codec.decode_with_confidence("... -------- ... ---"), ("SSO".to_string(), 0.75));
+    /// ```
+    pub fn decode_with_confidence(&self, morse: &str) -> (String, f64) {
+        let mut total = 0;
+        let mut valid = 0;
+        let mut text = String::with_capacity(morse.len());
+
+        for code in self.codes(morse).filter(|code| !code.is_empty()) {
+            total += 1;
+            if let Some(&ch) = self.decode_map.get(code) {
+                valid += 1;
+                text.push(ch);
+            }
+        }
+
+        let confidence = if total == 0 {
+            1.0
+        } else {
+            valid as f64 / total as f64
+        };
+
+        (self.alphabet.compose(text), confidence)
+    }
+
+    /// Decodes Morse code while preserving word boundaries.
+    ///
+    /// Splits the input on the word separator (`/` by default) first and
+    /// decodes each word separately, joining the results with a single
+    /// space. Leading, trailing, and consecutive separators never produce
+    /// empty words.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode, with `/` between words
+    ///
+    /// # Returns
+    ///
+    /// Decoded text with words separated by single spaces
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.decode_words(".... .. / -- .."), "HI MI");
+    /// assert_eq!(codec.decode_words("/ .... .. // -- .. /"), "HI MI");
+    /// ```
+    pub fn decode_words(&self, morse: &str) -> String {
+        morse
+            .split(self.word_separator.as_str())
+            .map(|word| self.decode(word))
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Decodes Morse code that uses any common word separator.
+    ///
+    /// Accepted word separators are the codec's own
+    /// [word separator](Self::set_word_separator), `/`, `|`, and runs of
+    /// two or more spaces. Each boundary becomes a single space in the
+    /// output, and empty words are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded text with words separated by single spaces
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.decode_flexible(".... .. | -- .."), "HI MI");
+    /// assert_eq!(codec.decode_flexible(".... ..   -- .. / ..."), "HI MI S");
+    ///
+    /// let mut custom = MorseCodec::new();
+    /// custom.set_word_separator("#").unwrap();
+    /// assert_eq!(custom.decode_flexible(".... .. # -- .. | ..."), "HI MI S");
+    /// ```
+    pub fn decode_flexible(&self, morse: &str) -> String {
+        morse
+            .split(self.word_separator.as_str())
+            .flat_map(|part| part.split(['/', '|']))
+            .flat_map(|part| part.split("  "))
+            .map(|word| self.decode(word))
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Rewrites Morse code into a canonical form.
+    ///
+    /// Runs of whitespace become single letter gaps, `/` and `|` word
+    /// separators become `" / "`, empty words are dropped, and the result
+    /// is trimmed. Normalizing twice gives the same result as normalizing
+    /// once, which makes the output suitable for storing and comparing.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to normalize
+    ///
+    /// # Returns
+    ///
+    /// Canonical Morse code string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let normalized = codec.normalize("  ....   ..//--  ..| ");
+    /// assert_eq!(normalized, ".... .. / -- ..");
+    /// assert_eq!(codec.normalize(&normalized), normalized);
+    /// ```
+    pub fn normalize(&self, morse: &str) -> String {
+        morse
+            .split(['/', '|'])
+            .map(|word| word.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    /// Decodes Morse code written with alternate dot and dash symbols.
+    ///
+    /// Maps the variants found in textbooks and pasted text to ASCII
+    /// before decoding:
+    ///
+    /// * Dots: `·` (middle dot) and `•` (bullet)
+    /// * Dashes: `−` (minus sign), `–` (en dash), and `—` (em dash)
+    /// * Spoken syllables: `di` and `dit` for dots and `dah` for dashes,
+    ///   optionally joined by hyphens within a letter, as in `di-dah`
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.decode_normalized("··· −−− ···"), "SOS");
+    /// assert_eq!(codec.decode_normalized("•– –•••"), "AB");
+    /// assert_eq!(codec.decode_normalized("Di-dah dah-di-di-dit / dit"), "AB E");
+    /// ```
+    pub fn decode_normalized(&self, morse: &str) -> String {
+        let canonical = morse
+            .split(' ')
+            .map(canonical_token)
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.decode(&canonical)
+    }
+
+    /// Decodes Morse code written with custom dot and dash symbols.
+    ///
+    /// Some datasets write dots and dashes as `0`/`1` or `E`/`T`. The given
+    /// symbols are translated to `.` and `-` before decoding; everything
+    /// else, including spaces and the word separator, is left as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode
+    /// * `dot` - Symbol used for dots
+    /// * `dash` - Symbol used for dashes
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.decode_mapped("000 111 000 / 0000 00", '0', '1'), "SOS HI");
+    /// assert_eq!(codec.decode_mapped("ET TEEE", 'E', 'T'), "AB");
+    /// ```
+    pub fn decode_mapped(&self, morse: &str, dot: char, dash: char) -> String {
+        let canonical: String = morse
+            .chars()
+            .map(|c| match c {
+                _ if c == dot => '.',
+                _ if c == dash => '-',
+                _ => c,
+            })
+            .collect();
+        self.decode(&canonical)
+    }
+
+    /// Decodes Morse code typed with underscores for dashes.
+    ///
+    /// Some fonts and keyboards lead users to type `_` instead of `-`, so
+    /// every `_` in the input is read as a dash before decoding.
+    ///
+    /// This only concerns the dot/dash symbols of the input. The underscore
+    /// *text* character has its own code, `..--.-`, which is what encoding
+    /// `"_"` produces and what this method decodes back to `_`; an
+    /// underscore typed into Morse input is never read as that character.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.decode_lenient("... ___ ..."), "SOS");
+    /// assert_eq!(codec.decode_lenient("._ -..."), "AB");
+    /// assert_eq!(codec.decode_lenient(&codec.encode("_")), "_");
+    /// ```
+    pub fn decode_lenient(&self, morse: &str) -> String {
+        self.decode(&morse.replace('_', "-"))
+    }
+
+    /// Decodes Morse code, inferring word breaks from the spacing.
+    ///
+    /// Some transcriptions lack explicit word separators and only hint at
+    /// words through wider gaps. The narrowest gap between letters in the
+    /// input is taken as the letter gap, and any gap at least `gap_ratio`
+    /// times as wide is read as a word break. Explicit word separators are
+    /// still honored.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode
+    /// * `gap_ratio` - How many times wider than the letter gap a gap must be
+    ///   to separate words (e.g. 2.0)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let morse = ".... ..   -- .   .-- . .-.. .-..";
+    /// assert_eq!(codec.decode(morse), "HIMEWELL");
+    /// assert_eq!(codec.decode_inferring_words(morse, 2.0), "HI ME WELL");
+    /// assert_eq!(codec.decode_inferring_words(morse, 4.0), "HIMEWELL");
+    /// ```
+    pub fn decode_inferring_words(&self, morse: &str, gap_ratio: f64) -> String {
+        // Each letter with the number of spaces before it.
+        let mut letters = Vec::new();
+        let mut gap = 0;
+        for (_, code) in self.code_spans(morse.trim()) {
+            gap += 1;
+            if !code.is_empty() {
+                letters.push((code, gap));
+                gap = 0;
+            }
+        }
+
+        let letter_gap = letters.iter().skip(1).map(|&(_, gap)| gap).min();
+        let word_gap = letter_gap.map_or(f64::INFINITY, |gap| gap as f64 * gap_ratio);
+        let mut text = String::with_capacity(morse.len());
+        let mut previous = None;
+
+        for (code, gap) in letters {
+            let explicit =
+                previous == Some(self.word_separator.as_str()) || code == self.word_separator;
+            if previous.is_some() && !explicit && gap as f64 >= word_gap {
+                text.push(' ');
+            }
+            if let Some(&ch) = self.decode_map.get(code) {
+                text.push(ch);
+            }
+            previous = Some(code);
+        }
+
+        self.alphabet.compose(text)
+    }
+
+    /// Decodes Morse code laid out with gaps written as runs of spaces.
+    ///
+    /// Interprets spaces by count, as in the keying timeline: fewer than
+    /// three spaces join elements of the same letter, three to six separate
+    /// letters, and seven or more separate words. Conventional Morse with
+    /// single spaces between letters therefore decodes as one long letter;
+    /// use [`decode`](Self::decode) for that.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code with 1-, 3-, and 7-space gaps
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.decode_spaced(". . .   - - -   . . ."), "SOS");
+    /// assert_eq!(codec.decode_spaced("....   ..       . . . .   . ."), "HI HI");
+    /// ```
+    pub fn decode_spaced(&self, morse: &str) -> String {
+        let mut canonical = String::new();
+        let mut spaces = 0;
+
+        for c in morse.trim_matches(' ').chars() {
+            if c == ' ' {
+                spaces += 1;
+                continue;
+            }
+
+            if spaces >= 7 {
+                canonical.push(' ');
+                canonical.push_str(&self.word_separator);
+                canonical.push(' ');
+            } else if spaces >= 3 {
+                canonical.push(' ');
+            }
+            spaces = 0;
+            canonical.push(c);
+        }
+
+        self.decode(&canonical)
+    }
+
+    /// Decodes glyph Morse code produced by
+    /// [`encode_symbols`](Self::encode_symbols).
+    ///
+    /// Uses the separators configured with
+    /// [`set_symbol_separators`](Self::set_symbol_separators). Empty letters
+    /// left by repeated separators are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - Glyph string with `●` for dots and `▬` for dashes
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.decode_symbols("●●● ▬▬▬ ●●● | ●●●● ●●"), "SOS HI");
+    /// let text = "PRINT ME";
+    /// assert_eq!(codec.decode_symbols(&codec.encode_symbols(text)), text);
+    /// ```
+    pub fn decode_symbols(&self, symbols: &str) -> String {
+        let (letter_sep, word_sep) = &self.symbol_separators;
+        let word_break = format!(" {} ", self.word_separator);
+
+        let canonical = symbols
+            .split(word_sep.as_str())
+            .map(|word| {
+                word.split(letter_sep.as_str())
+                    .filter(|letter| !letter.trim().is_empty())
+                    .map(|letter| {
+                        letter
+                            .trim()
+                            .chars()
+                            .map(|glyph| match glyph {
+                                '●' => '.',
+                                '▬' => '-',
+                                other => other,
+                            })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(&word_break);
+
+        self.decode(&canonical)
+    }
+
+    /// Decodes Morse code token by token, reporting where each token starts.
+    ///
+    /// Useful for interactive decoders that highlight the source Morse
+    /// alongside the decoded characters. Empty tokens from repeated spaces
+    /// are skipped. Characters are reported as transmitted, so Wabun voiced
+    /// kana appear as the base kana followed by a separate mark.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Iterator of decode events in input order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{DecodeEvent, MorseCodec};
+    /// let codec = MorseCodec::new();
+    ///
+    /// let events: Vec<_> = codec.decode_events("... ......").collect();
+    /// assert_eq!(
+    ///     events,
+    ///     vec![
+    ///         DecodeEvent { token: "...", decoded: Some('S'), start: 0 },
+    ///         DecodeEvent { token: "......", decoded: None, start: 4 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn decode_events<'a>(&'a self, morse: &'a str) -> impl Iterator<Item = DecodeEvent<'a>> {
+        self.code_spans(morse)
+            .filter(|(_, code)| !code.is_empty())
+            .map(|(start, token)| DecodeEvent {
+                token,
+                decoded: self.decode_map.get(token).copied(),
+                start,
+            })
+    }
+
+    /// Decodes Morse code, failing on the first unknown sequence.
+    ///
+    /// Unlike [`decode`](Self::decode), invalid sequences are reported
+    /// instead of being dropped. Repeated spaces between tokens are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode (space-separated)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text, or a `DecodeError` describing the offending token
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(codec.try_decode("... --- ...").unwrap(), "SOS");
+    ///
+    /// let err = codec.try_decode("... --- ...... ...").unwrap_err();
+    /// assert_eq!(err.token, "......");
+    /// assert_eq!(err.position, 2);
+    /// ```
+    pub fn try_decode(&self, morse: &str) -> Result<String, DecodeError> {
+        let text = self
+            .codes(morse)
+            .filter(|code| !code.is_empty())
+            .enumerate()
+            .map(|(position, code)| {
+                self.decode_map
+                    .get(code)
+                    .copied()
+                    .ok_or_else(|| DecodeError {
+                        token: code.to_string(),
+                        position,
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(self.alphabet.compose(text))
+    }
+
+    /// Decodes Morse code whose letter gaps were lost.
+    ///
+    /// Tries every way of splitting a run of dots and dashes into known
+    /// codes, which is useful for puzzle-style Morse where spacing is
+    /// missing. Any other characters, including spaces, are ignored. The
+    /// number of results is capped at 64 because it grows exponentially
+    /// with the input length.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Dots and dashes without letter gaps
+    ///
+    /// # Returns
+    ///
+    /// Every plausible decoding, fewest-element codes first
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let decodings = codec.decode_ambiguous(".-");
+    /// assert_eq!(decodings, vec!["ET", "A"]);
+    ///
+    /// let decodings = codec.decode_ambiguous("...---");
+    /// assert!(decodings.contains(&"SO".to_string()));
+    /// assert!(codec.decode_ambiguous("...---...").len() <= 64);
+    /// ```
+    pub fn decode_ambiguous(&self, morse: &str) -> Vec<String> {
+        let elements: String = morse.chars().filter(|c| matches!(c, '.' | '-')).collect();
+        let max_len = self.decode_map.keys().map(String::len).max().unwrap_or(0);
+
+        // complete[i] is true when elements[i..] splits into known codes,
+        // so the search below never explores a dead end.
+        let mut complete = vec![false; elements.len() + 1];
+        complete[elements.len()] = true;
+        for start in (0..elements.len()).rev() {
+            complete[start] = (1..=max_len.min(elements.len() - start)).any(|len| {
+                complete[start + len] && self.decode_map.contains_key(&elements[start..start + len])
+            });
+        }
+
+        let mut decodings = Vec::new();
+        if !elements.is_empty() && complete[0] {
+            self.segment(
+                &elements,
+                0,
+                max_len,
+                &complete,
+                &mut String::new(),
+                &mut decodings,
+            );
+        }
+        decodings
+    }
+
+    /// Decodes key-down and key-up durations captured from a real key.
+    ///
+    /// Each key-down span shorter than two units is read as a dot and
+    /// anything longer as a dash. Key-up spans shorter than two units
+    /// separate elements, up to five units separate letters, and longer
+    /// ones separate words. Without a speed hint, the speed is estimated
+    /// with [`estimate_wpm`](Self::estimate_wpm).
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - `(key_down, duration_ms)` spans in order, as produced by
+    ///   [`to_flash_pattern`](Self::to_flash_pattern)
+    /// * `wpm_hint` - Sending speed if known (dot_duration_ms = 1200 / WPM)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let events = [
+    ///     (true, 58.0),
+    ///     (false, 65.0),
+    ///     (true, 190.0),
+    ///     (false, 420.0),
+    ///     (true, 62.0),
+    /// ];
+    /// assert_eq!(codec.decode_timings(&events, None), "A E");
+    ///
+    /// let pattern = codec.to_flash_pattern(&codec.encode("SOS"), 20.0).unwrap();
+    /// assert_eq!(codec.decode_timings(&pattern, Some(20.0)), "SOS");
+    /// ```
+    pub fn decode_timings(&self, events: &[(bool, f64)], wpm_hint: Option<f64>) -> String {
+        let wpm = wpm_hint.unwrap_or_else(|| self.estimate_wpm(events));
+        let unit = 1200.0 / wpm;
+        if !unit.is_finite() {
+            return String::new();
+        }
+
+        let mut morse = String::new();
+        for &(on, duration) in events {
+            let units = duration / unit;
+            if on {
+                morse.push(if units < 2.0 { '.' } else { '-' });
+            } else if units >= 5.0 {
+                morse.push(' ');
+                morse.push_str(&self.word_separator);
+                morse.push(' ');
+            } else if units >= 2.0 {
+                morse.push(' ');
+            }
+        }
+
+        self.decode(&morse)
+    }
+
+    /// Estimates the sending speed of captured key durations.
+    ///
+    /// Splits the key-down spans into dots and dashes with two-means
+    /// clustering, then fits the unit length to both groups, counting a
+    /// dash as three units. When every span is about the same length, all
+    /// of them are treated as dots.
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - `(key_down, duration_ms)` spans in order
+    ///
+    /// # Returns
+    ///
+    /// Estimated words per minute, or 0.0 if there are no key-down spans
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let pattern = codec.to_flash_pattern(&codec.encode("PARIS"), 15.0).unwrap();
+    /// assert!((codec.estimate_wpm(&pattern) - 15.0).abs() < 1e-9);
+    ///
+    /// let events = [(true, 55.0), (false, 60.0), (true, 190.0), (true, 64.0)];
+    /// assert!((codec.estimate_wpm(&events) - 20.0).abs() < 1.0);
+    ///
+    /// assert_eq!(codec.estimate_wpm(&[(true, 100.0), (true, 100.0)]), 12.0);
+    /// assert_eq!(codec.estimate_wpm(&[]), 0.0);
+    /// ```
+    pub fn estimate_wpm(&self, events: &[(bool, f64)]) -> f64 {
+        let durations: Vec<f64> = events
+            .iter()
+            .filter(|&&(on, duration)| on && duration > 0.0)
+            .map(|&(_, duration)| duration)
+            .collect();
+        if durations.is_empty() {
+            return 0.0;
+        }
+
+        let mut dot = durations.iter().copied().fold(f64::INFINITY, f64::min);
+        let mut dash = durations.iter().copied().fold(0.0, f64::max);
+
+        // A dash is nominally three dots, so a narrower spread means only
+        // one kind of element was sent.
+        if dash < 2.0 * dot {
+            let mean = durations.iter().sum::<f64>() / durations.len() as f64;
+            return 1200.0 / mean;
+        }
+
+        let (mut dots, mut dashes) = (Vec::new(), Vec::new());
+        for _ in 0..16 {
+            (dots, dashes) = durations
+                .iter()
+                .partition(|&&duration| duration - dot < dash - duration);
+
+            let next_dot = dots.iter().sum::<f64>() / dots.len() as f64;
+            let next_dash = dashes.iter().sum::<f64>() / dashes.len() as f64;
+            if next_dot == dot && next_dash == dash {
+                break;
+            }
+            dot = next_dot;
+            dash = next_dash;
+        }
+
+        let total: f64 = durations.iter().sum();
+        let units = dots.len() as f64 + 3.0 * dashes.len() as f64;
+        1200.0 / (total / units)
+    }
+
+    /// Decodes Morse code and spells the result with NATO phonetic words.
+    ///
+    /// Letters and digits are read out as their phonetic words ("A" becomes
+    /// "Alpha"), which helps screen readers and voice output announce
+    /// callsigns clearly. Other characters are kept as they are, and word
+    /// breaks appear as `/`.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - The Morse code to decode
+    ///
+    /// # Returns
+    ///
+    /// Space-separated phonetic words
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(
+    ///     codec.decode_phonetic("-.. . / .-- .---- .- .--"),
+    ///     "Delta Echo / Whiskey One Alpha Whiskey"
+    /// );
+    /// ```
+    pub fn decode_phonetic(&self, morse: &str) -> String {
+        let text = self.decode(morse);
+        let mut words = Vec::new();
+
+        for word in text.split_whitespace() {
+            if !words.is_empty() {
+                words.push("/".to_string());
+            }
+            words.extend(word.chars().map(|ch| match phonetic::word(ch) {
+                Some(spoken) => spoken.to_string(),
+                None => ch.to_string(),
+            }));
+        }
+
+        words.join(" ")
+    }
+
+    /// Expands common Q-codes and CW abbreviations into plain language.
+    ///
+    /// Works on whitespace-separated tokens, so it can be applied to text
+    /// before encoding or after decoding. Tokens are matched
+    /// case-insensitively and must match exactly; anything else, including
+    /// tokens with attached punctuation, is left unchanged. Tokens in the
+    /// output are separated by single spaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text containing abbreviations
+    ///
+    /// # Returns
+    ///
+    /// Text with known abbreviations replaced by their meanings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    /// assert_eq!(
+    ///     codec.expand_abbreviations("QTH PARIS 73"),
+    ///     "location PARIS best regards"
+    /// );
+    /// ```
+    pub fn expand_abbreviations(&self, text: &str) -> String {
+        text.split_whitespace()
+            .map(|token| abbreviation::expand(token).unwrap_or(token))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Converts Morse code into on/off segments for visual signalling.
+    ///
+    /// Follows the same timing as audio playback, so the pattern can drive
+    /// an LED, a screen flash, or a signal-lamp simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to convert
+    /// * `wpm` - Words per minute, 1-60 (dot_duration_ms = 1200 / WPM)
+    ///
+    /// # Returns
+    ///
+    /// `(is_on, duration_ms)` segments in order, or a `TimingError` if the
+    /// speed is out of range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let pattern = codec.to_flash_pattern(".-", 20.0).unwrap();
+    /// assert_eq!(pattern[0], (true, 60.0));
+    /// assert_eq!(pattern[2], (true, 180.0));
+    ///
+    /// assert!(codec.to_flash_pattern(".-", 0.0).is_err());
+    /// assert!(codec.to_flash_pattern(".-", f64::NAN).is_err());
+    /// ```
+    pub fn to_flash_pattern(&self, morse: &str, wpm: f64) -> Result<Vec<(bool, f64)>, TimingError> {
+        timing::check_wpm(wpm)?;
+
+        Ok(MorseTiming::new(wpm)
+            .timeline(morse)
+            .into_iter()
+            .map(|event| (event.on, event.duration_ms))
+            .collect())
+    }
+
+    /// Converts Morse code into a rhythm of notes and rests.
+    ///
+    /// Uses standard timing with one beat per dot, so a dot is a one-beat
+    /// note, a dash a three-beat note, and the element, letter, and word gaps
+    /// are rests of one, three, and seven beats. The result is plain data
+    /// that any notation renderer can consume.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to convert
+    ///
+    /// # Returns
+    ///
+    /// Notes and rests in order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{MorseCodec, Note};
+    /// let codec = MorseCodec::new();
+    ///
+    /// let note = |beats| Note { pitch_on: true, beats };
+    /// let rest = |beats| Note { pitch_on: false, beats };
+    /// assert_eq!(
+    ///     codec.to_rhythm(".- ."),
+    ///     vec![note(1.0), rest(1.0), note(3.0), rest(3.0), note(1.0)]
+    /// );
+    /// ```
+    pub fn to_rhythm(&self, morse: &str) -> Vec<Note> {
+        let timing = MorseTiming::new(20.0);
+        let beat_ms = timing.dot_duration();
+
+        timing
+            .timeline(morse)
+            .into_iter()
+            .map(|event| Note {
+                pitch_on: event.on,
+                beats: event.duration_ms / beat_ms,
+            })
+            .collect()
+    }
+
+    /// Traces the keying envelope of Morse code as SVG path data.
+    ///
+    /// The path runs along the bottom of the box while the key is up and
+    /// along the top while it is down, spanning the whole transmission from
+    /// left to right. Coordinates are rounded to two decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to trace
+    /// * `wpm` - Words per minute, 1-60 (dot_duration_ms = 1200 / WPM)
+    /// * `width` - Width of the box the path is scaled to
+    /// * `height` - Height of the box the path is scaled to
+    ///
+    /// # Returns
+    ///
+    /// Value for the `d` attribute of an SVG `<path>`, or a `TimingError`
+    /// if the speed is out of range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// // ".-" lasts 5 units, so each unit is 20 wide.
+    /// let path = codec.to_svg_path(".-", 20.0, 100.0, 10.0).unwrap();
+    /// assert!(path.starts_with("M0,"));
+    /// assert_eq!(path, "M0,10V0H20V10H40V0H100V10");
+    ///
+    /// assert!(codec.to_svg_path(".-", f64::NAN, 100.0, 10.0).is_err());
+    /// ```
+    pub fn to_svg_path(
+        &self,
+        morse: &str,
+        wpm: f64,
+        width: f64,
+        height: f64,
+    ) -> Result<String, TimingError> {
+        timing::check_wpm(wpm)?;
+
+        let timeline = MorseTiming::new(wpm).timeline(morse);
+        let total_ms = timeline.last().map_or(0.0, ToneEvent::end_ms);
+        let mut path = format!("M0,{}", svg_number(height));
+
+        if total_ms <= 0.0 {
+            path.push_str(&format!("H{}", svg_number(width)));
+            return Ok(path);
+        }
+
+        let scale = width / total_ms;
+        for event in timeline.iter().skip_while(|event| !event.on) {
+            if event.start_ms > 0.0 {
+                path.push_str(&format!("H{}", svg_number(event.start_ms * scale)));
+            }
+            if event.on {
+                path.push_str("V0");
+            } else {
+                path.push_str(&format!("V{}", svg_number(height)));
+            }
+        }
+
+        path.push_str(&format!("H{}", svg_number(width)));
+        if timeline.last().is_some_and(|event| event.on) {
+            path.push_str(&format!("V{}", svg_number(height)));
+        }
+
+        Ok(path)
+    }
+
+    /// Samples the keying envelope of Morse code.
+    ///
+    /// Produces one value per sample, `true` while the key is down, which
+    /// can be used to modulate any carrier. The whole signal is held in
+    /// memory, so long messages at high sample rates get large quickly;
+    /// prefer [`to_keying_signal_iter`](Self::to_keying_signal_iter) there.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to key
+    /// * `wpm` - Words per minute, 1-60 (dot_duration_ms = 1200 / WPM)
+    /// * `sample_rate` - Samples per second
+    ///
+    /// # Returns
+    ///
+    /// Key state for every sample of the transmission, or a `TimingError`
+    /// if the speed is out of range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// // At 20 WPM and 1000 Hz, one dot lasts 60 samples.
+    /// let signal = codec.to_keying_signal(".-", 20.0, 1000).unwrap();
+    /// assert_eq!(signal.len(), 300);
+    /// assert!(signal[..60].iter().all(|&on| on));
+    /// assert!(signal[60..120].iter().all(|&on| !on));
+    /// assert!(signal[120..].iter().all(|&on| on));
+    ///
+    /// assert!(codec.to_keying_signal(".", 0.0, 8000).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_keying_signal(
+        &self,
+        morse: &str,
+        wpm: f64,
+        sample_rate: u32,
+    ) -> Result<Vec<bool>, TimingError> {
+        timing::check_wpm(wpm)?;
+
+        let rate = f64::from(sample_rate);
+        let to_sample = |ms: f64| (ms * rate / 1000.0).round() as usize;
+
+        let timeline = MorseTiming::new(wpm).timeline(morse);
+        let total_ms = timeline.last().map_or(0.0, ToneEvent::end_ms);
+        let mut signal = vec![false; to_sample(total_ms)];
+
+        for event in timeline.iter().filter(|event| event.on) {
+            let start = to_sample(event.start_ms);
+            let end = to_sample(event.end_ms()).min(signal.len());
+            signal[start..end].fill(true);
+        }
+
+        Ok(signal)
+    }
+
+    /// Lazily samples the keying envelope of Morse code.
+    ///
+    /// Yields the same values as
+    /// [`to_keying_signal`](Self::to_keying_signal) one sample at a time,
+    /// so long messages can be streamed into a modulator or encoder
+    /// without buffering the whole signal.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to key
+    /// * `wpm` - Words per minute, 1-60 (dot_duration_ms = 1200 / WPM)
+    /// * `sample_rate` - Samples per second
+    ///
+    /// # Returns
+    ///
+    /// Iterator over the key state of every sample, or a `TimingError` if
+    /// the speed is out of range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let lazy: Vec<bool> = codec
+    ///     .to_keying_signal_iter("... ---", 20.0, 8000)
+    ///     .unwrap()
+    ///     .collect();
+    /// assert_eq!(lazy, codec.to_keying_signal("... ---", 20.0, 8000).unwrap());
+    ///
+    /// assert!(codec.to_keying_signal_iter(".", -5.0, 8000).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_keying_signal_iter(
+        &self,
+        morse: &str,
+        wpm: f64,
+        sample_rate: u32,
+    ) -> Result<impl Iterator<Item = bool>, TimingError> {
+        timing::check_wpm(wpm)?;
+
+        let rate = f64::from(sample_rate);
+        let to_sample = move |ms: f64| (ms * rate / 1000.0).round() as usize;
+
+        Ok(MorseTiming::new(wpm)
+            .timeline(morse)
+            .into_iter()
+            .flat_map(move |event| {
+                let samples = to_sample(event.end_ms()) - to_sample(event.start_ms);
+                core::iter::repeat_n(event.on, samples)
+            }))
+    }
+
+    /// Lists the characters in `text` that [`encode`](Self::encode) would drop.
+    fn find_unsupported(&self, text: &str) -> Vec<(usize, char)> {
+        self.unsupported_spans(text)
+            .into_iter()
+            .map(|(start, _, ch)| (start, ch))
+            .collect()
+    }
+
+    /// Encodes text, emitting `substitute(ch)` as the token for every
+    /// unsupported character instead of dropping it.
+    fn encode_substituting(&self, text: &str, substitute: impl Fn(char) -> String) -> String {
+        let mut tokens = Vec::new();
+        let mut encoded_to = 0;
+
+        for (start, end, ch) in self.unsupported_spans(text) {
+            tokens.push(self.encode(&text[encoded_to..start]));
+            tokens.push(substitute(ch));
+            encoded_to = end;
+        }
+        tokens.push(self.encode(&text[encoded_to..]));
+
+        tokens.retain(|token| !token.is_empty());
+        tokens.join(" ")
+    }
+
+    /// Lists the start, end, and character of every unsupported character,
+    /// spanning any combining mark composed into it.
+    fn unsupported_spans(&self, text: &str) -> Vec<(usize, usize, char)> {
+        let mut unsupported = Vec::new();
+        let mut skip_to = 0;
+
+        for (position, ch) in text.char_indices() {
+            if position < skip_to {
+                continue;
+            }
+
+            if let Some((_, len)) = prosign::parse(&text[position..]) {
+                skip_to = position + len;
+                continue;
+            }
+
+            let mut ch = ch;
+            let mut end = position + ch.len_utf8();
+            if let Some(mark) = text[end..].chars().next() {
+                if let Some(composed) = alphabet::compose_mark(to_upper(ch), mark) {
+                    end += mark.len_utf8();
+                    skip_to = end;
+                    ch = composed;
+                }
+            }
+
+            let supported = ch.to_uppercase().all(|upper| {
+                let (base, mark) = self.alphabet.decompose(upper);
+                self.encode_map.contains_key(&base)
+                    && mark.is_none_or(|mark| self.encode_map.contains_key(&mark))
+            });
+
+            if !supported {
+                unsupported.push((position, end, ch));
+            }
+        }
+
+        unsupported
+    }
+
+    /// Counts the elements, characters, and words of encoded text.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to analyze
+    ///
+    /// # Returns
+    ///
+    /// Statistics derived from the encoded form of `text`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let stats = codec.stats("SOS SOS");
+    /// assert_eq!(stats.dots, 12);
+    /// assert_eq!(stats.dashes, 6);
+    /// assert_eq!(stats.elements, 18);
+    /// assert_eq!(stats.characters, 6);
+    /// assert_eq!(stats.words, 2);
+    /// ```
+    pub fn stats(&self, text: &str) -> MorseStats {
+        let mut stats = MorseStats::default();
+        let mut in_word = false;
+
+        for (ch, code) in self.encode_iter(text) {
+            if ch == ' ' {
+                in_word = false;
+                continue;
+            }
+
+            if !in_word {
+                stats.words += 1;
+                in_word = true;
+            }
+
+            stats.characters += 1;
+            for element in code.chars() {
+                match element {
+                    '.' => stats.dots += 1,
+                    '-' => stats.dashes += 1,
+                    _ => {}
+                }
+            }
+        }
+
+        stats.elements = stats.dots + stats.dashes;
+        stats
+    }
+
+    /// Scores how hard text is to send, for ordering practice material.
+    ///
+    /// # Formula
+    ///
+    /// difficulty = 0.5 * (avg_elements - 1) / 5 + 0.5 * dash_ratio
+    ///
+    /// where `avg_elements` is the average number of elements per character
+    /// (capped at 6) and `dash_ratio` is the fraction of elements that are
+    /// dashes. Longer characters and more dashes make text harder.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to score
+    ///
+    /// # Returns
+    ///
+    /// Score between 0.0 (easiest) and 1.0 (hardest); 0.0 for text with
+    /// no encodable characters
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.difficulty("EE"), 0.0);
+    /// assert!(codec.difficulty("SEE") < codec.difficulty("QUO"));
+    /// assert!(codec.difficulty("0") <= 1.0);
+    /// ```
+    pub fn difficulty(&self, text: &str) -> f64 {
+        let stats = self.stats(text);
+        if stats.elements == 0 {
+            return 0.0;
+        }
+
+        let avg_elements = stats.elements as f64 / stats.characters as f64;
+        let length_score = (avg_elements.min(6.0) - 1.0) / 5.0;
+        let dash_ratio = stats.dashes as f64 / stats.elements as f64;
+
+        0.5 * length_score + 0.5 * dash_ratio
+    }
+
+    /// Aligns sent Morse code against the expected code, element by element.
+    ///
+    /// Finds an alignment with the fewest insertions, deletions and
+    /// substitutions. Letter and word separators count as elements, so
+    /// mistakes stay attached to the letter they were made in. When several
+    /// alignments are equally short, matches are preferred as early as
+    /// possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The Morse code that should have been sent
+    /// * `actual` - The Morse code that was sent
+    ///
+    /// # Returns
+    ///
+    /// Alignment steps in order, covering every element of both inputs
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{DiffOp, MorseCodec};
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(
+    ///     codec.diff("...-", "..-"),
+    ///     vec![
+    ///         DiffOp::Match('.'),
+    ///         DiffOp::Match('.'),
+    ///         DiffOp::Delete('.'),
+    ///         DiffOp::Match('-'),
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     codec.diff(".-", "--"),
+    ///     vec![
+    ///         DiffOp::Substitute { expected: '.', actual: '-' },
+    ///         DiffOp::Match('-'),
+    ///     ]
+    /// );
+    /// ```
+    pub fn diff(&self, expected: &str, actual: &str) -> Vec<DiffOp> {
+        let expected: Vec<char> = expected.trim().chars().collect();
+        let actual: Vec<char> = actual.trim().chars().collect();
+        let (rows, cols) = (expected.len() + 1, actual.len() + 1);
+
+        // cost[i * cols + j] is the edit distance between expected[i..] and
+        // actual[j..], so the alignment can be read off front to back.
+        let mut cost = vec![0usize; rows * cols];
+        for i in (0..rows).rev() {
+            for j in (0..cols).rev() {
+                cost[i * cols + j] = if i == expected.len() {
+                    actual.len() - j
+                } else if j == actual.len() {
+                    expected.len() - i
+                } else {
+                    let step = usize::from(expected[i] != actual[j]);
+                    (cost[(i + 1) * cols + j + 1] + step)
+                        .min(cost[(i + 1) * cols + j] + 1)
+                        .min(cost[i * cols + j + 1] + 1)
+                };
+            }
+        }
+
+        let mut ops = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < expected.len() || j < actual.len() {
+            let here = cost[i * cols + j];
+            if i < expected.len() && j < actual.len() {
+                let step = usize::from(expected[i] != actual[j]);
+                if here == cost[(i + 1) * cols + j + 1] + step {
+                    ops.push(if step == 0 {
+                        DiffOp::Match(expected[i])
+                    } else {
+                        DiffOp::Substitute {
+                            expected: expected[i],
+                            actual: actual[j],
+                        }
+                    });
+                    i += 1;
+                    j += 1;
+                    continue;
+                }
+            }
+
+            if i < expected.len() && (j == actual.len() || here == cost[(i + 1) * cols + j] + 1) {
+                ops.push(DiffOp::Delete(expected[i]));
+                i += 1;
+            } else {
+                ops.push(DiffOp::Insert(actual[j]));
+                j += 1;
+            }
+        }
+
+        ops
+    }
+
+    /// Estimates how long text takes to send at a given speed.
+    ///
+    /// Encodes the text and applies the same timing rules as playback, so
+    /// transmission times can be computed natively or on a server without an
+    /// `AudioContext`. Spaces count as word gaps, including a trailing one.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to send
+    /// * `wpm` - Words per minute with standard timing, 1-60
+    ///
+    /// # Returns
+    ///
+    /// Total duration in milliseconds, or a `TimingError` if the speed is
+    /// out of range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// assert_eq!(codec.transmission_time_ms("PARIS ", 20.0).unwrap(), 3000.0);
+    /// assert_eq!(codec.transmission_time_ms("SOS", 20.0).unwrap(), 1620.0);
+    /// assert!(codec.transmission_time_ms("SOS", 0.0).is_err());
+    /// ```
+    pub fn transmission_time_ms(&self, text: &str, wpm: f64) -> Result<f64, TimingError> {
+        timing::check_wpm(wpm)?;
+
+        let morse = self
+            .encode_iter(text)
+            .map(|(ch, code)| if ch == ' ' { "/" } else { code })
+            .collect::<Vec<_>>()
+            .join(" ");
+        Ok(MorseTiming::new(wpm).duration_ms(&morse))
+    }
+
+    /// Splits text into chunks that each fit within a time limit.
+    ///
+    /// Whole words are grouped greedily: a chunk grows until adding the next
+    /// word would make its transmission exceed `max_ms`. A single word that
+    /// is too long on its own gets a chunk to itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to split
+    /// * `wpm` - Words per minute with standard timing, 1-60
+    /// * `max_ms` - Longest allowed transmission per chunk in milliseconds,
+    ///   positive and finite
+    ///
+    /// # Returns
+    ///
+    /// Chunks of space-separated words, in order, or a `TimingError` if the
+    /// speed or the limit is out of range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// // At 20 WPM "SOS" lasts 1620 ms and "SOS SOS" 3660 ms.
+    /// assert_eq!(
+    ///     codec.split_by_duration("SOS SOS SOS", 20.0, 4000.0).unwrap(),
+    ///     vec!["SOS SOS", "SOS"]
+    /// );
+    /// assert_eq!(
+    ///     codec.split_by_duration("SOS HI", 20.0, 1000.0).unwrap(),
+    ///     vec!["SOS", "HI"]
+    /// );
+    ///
+    /// assert!(codec.split_by_duration("SOS HI", 0.0, 1000.0).is_err());
+    /// let err = codec.split_by_duration("SOS HI", 20.0, f64::INFINITY).unwrap_err();
+    /// assert_eq!(err.parameter, "max_ms");
+    /// ```
+    pub fn split_by_duration(
+        &self,
+        text: &str,
+        wpm: f64,
+        max_ms: f64,
+    ) -> Result<Vec<String>, TimingError> {
+        timing::check_wpm(wpm)?;
+        if !(max_ms > 0.0 && max_ms.is_finite()) {
+            return Err(TimingError {
+                parameter: "max_ms",
+                value: max_ms,
+            });
+        }
+
+        let mut chunks: Vec<String> = Vec::new();
+        let mut current = String::new();
+
+        for word in text.split_whitespace() {
+            if current.is_empty() {
+                current.push_str(word);
+                continue;
+            }
+
+            let candidate = format!("{} {}", current, word);
+            if self.transmission_time_ms(&candidate, wpm)? > max_ms {
+                chunks.push(core::mem::replace(&mut current, word.to_string()));
+            } else {
+                current = candidate;
+            }
+        }
+
+        if !current.is_empty() {
+            chunks.push(current);
+        }
+
+        Ok(chunks)
+    }
+
+    /// Finds the character for a code, using the dot/dash index when the
+    /// code fits in it.
+    fn lookup(&self, code: &str) -> Option<char> {
+        match code_index(code) {
+            Some(index) => self.decode_index[index],
+            None => self.decode_map.get(code).copied(),
+        }
+    }
+
+    /// Adds a code to the decode map and index.
+    fn map_code(&mut self, code: &str, ch: char) {
+        if let Some(index) = code_index(code) {
+            self.decode_index[index] = Some(ch);
+        }
+        self.decode_map.insert(code.to_string(), ch);
+    }
+
+    /// Removes a code from the decode map and index.
+    fn unmap_code(&mut self, code: &str) {
+        if let Some(index) = code_index(code) {
+            self.decode_index[index] = None;
+        }
+        self.decode_map.remove(code);
+    }
+
+    /// Collects the decodings of `elements[start..]` for `decode_ambiguous`.
+    fn segment(
+        &self,
+        elements: &str,
+        start: usize,
+        max_len: usize,
+        complete: &[bool],
+        prefix: &mut String,
+        decodings: &mut Vec<String>,
+    ) {
+        if start == elements.len() {
+            decodings.push(self.alphabet.compose(prefix.clone()));
+            return;
+        }
+
+        for len in 1..=max_len.min(elements.len() - start) {
+            if decodings.len() >= MAX_AMBIGUOUS_DECODINGS {
+                return;
+            }
+            if !complete[start + len] {
+                continue;
+            }
+            if let Some(&ch) = self.decode_map.get(&elements[start..start + len]) {
+                prefix.push(ch);
+                self.segment(elements, start + len, max_len, complete, prefix, decodings);
+                prefix.pop();
+            }
+        }
+    }
+
+    /// Looks up the code sent for an uppercase character, applying cut
+    /// numbers if enabled.
+    fn code_for(&self, ch: char) -> Option<&str> {
+        let ch = match CUT_NUMBERS.iter().find(|&&(digit, _)| digit == ch) {
+            Some(&(_, letter)) if self.cut_numbers => letter,
+            _ => ch,
+        };
+        self.encode_map.get(&ch).map(String::as_str)
+    }
+
+    /// Splits Morse code into the tokens of individual characters.
+    fn codes<'a>(&'a self, morse: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.code_spans(morse).map(|(_, code)| code)
+    }
+
+    /// Splits Morse code into tokens along with their byte offsets.
+    fn code_spans<'a>(&'a self, morse: &'a str) -> Codes<'a> {
+        Codes {
+            codec: self,
+            morse,
+            position: Some(0),
+        }
+    }
+
+    /// Finds the most common character whose code is one edit from `code`.
+    fn nearest_char(&self, code: &str) -> Option<char> {
+        let elements: Vec<char> = code.chars().collect();
+        let mut candidates = Vec::new();
+
+        for i in 0..=elements.len() {
+            for element in ['.', '-'] {
+                let mut inserted = elements.clone();
+                inserted.insert(i, element);
+                candidates.push(inserted);
+            }
+        }
+
+        for i in 0..elements.len() {
+            let mut deleted = elements.clone();
+            deleted.remove(i);
+            candidates.push(deleted);
+
+            let mut substituted = elements.clone();
+            substituted[i] = if elements[i] == '.' { '-' } else { '.' };
+            candidates.push(substituted);
+        }
+
+        candidates
+            .iter()
+            .filter_map(|candidate| {
+                let candidate: String = candidate.iter().collect();
+                self.decode_map.get(candidate.as_str()).copied()
+            })
+            .min_by_key(|&ch| (frequency_rank(ch), ch))
+    }
+
+    /// Encodes text into a timestamped [`MorseMessage`].
+    ///
+    /// The timestamp is taken from `Date.now()` in the browser and from the
+    /// system clock in native builds.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to encode
+    ///
+    /// # Returns
+    ///
+    /// Message holding the original text, its Morse code, and the current time
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseCodec;
+    /// let codec = MorseCodec::new();
+    ///
+    /// let message = codec.to_message("SOS");
+    /// assert_eq!(message.morse, "... --- ...");
+    /// assert!(message.timestamp > 0.0);
+    /// assert_eq!(codec.from_morse_message(&message), "SOS");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_message(&self, text: &str) -> MorseMessage {
+        MorseMessage {
+            text: text.to_string(),
+            morse: self.encode(text),
+            timestamp: now_ms(),
+        }
+    }
+
+    /// Decodes the Morse code stored in a [`MorseMessage`].
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    pub fn from_morse_message(&self, message: &MorseMessage) -> String {
+        self.decode(&message.morse)
+    }
+}
+
+/// Returns the shared codec used by the module-level helpers.
+#[cfg(feature = "std")]
+fn shared_codec() -> &'static MorseCodec {
+    static CODEC: OnceLock<MorseCodec> = OnceLock::new();
+    CODEC.get_or_init(MorseCodec::new)
+}
+
+/// Encodes text into International Morse Code.
+///
+/// Convenience wrapper around a lazily-initialized shared [`MorseCodec`];
+/// use the struct directly for other alphabets or custom mappings.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(morsewave::encode("SOS"), "... --- ...");
+/// ```
+#[cfg(feature = "std")]
+pub fn encode(text: &str) -> String {
+    shared_codec().encode(text)
+}
+
+/// Decodes International Morse Code into text.
+///
+/// Convenience wrapper around a lazily-initialized shared [`MorseCodec`];
+/// use the struct directly for other alphabets or custom mappings.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(morsewave::decode("... --- ..."), "SOS");
+/// ```
+#[cfg(feature = "std")]
+pub fn decode(morse: &str) -> String {
+    shared_codec().decode(morse)
+}
+
+/// Splits Morse code into character tokens for [`MorseCodec`].
+///
+/// Tokens are separated by single spaces. For alphabets with spaced codes,
+/// a token is joined with the next one whenever the pair forms a known code.
+struct Codes<'a> {
+    codec: &'a MorseCodec,
+    morse: &'a str,
+    position: Option<usize>,
+}
+
+impl<'a> Iterator for Codes<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.position?;
+        let rest = &self.morse[start..];
+        let Some(first) = rest.find(' ') else {
+            self.position = None;
+            return Some((start, rest));
+        };
+
+        let mut end = first;
+        if self.codec.alphabet.has_spaced_codes() && first > 0 {
+            let second = rest[first + 1..]
+                .find(' ')
+                .map_or(rest.len(), |i| first + 1 + i);
+
+            if self.codec.decode_map.contains_key(&rest[..second]) {
+                end = second;
+            }
+        }
+
+        self.position = (end < rest.len()).then_some(start + end + 1);
+        Some((start, &rest[..end]))
+    }
+}
+
+/// Formats an SVG coordinate with at most two decimals.
+fn svg_number(value: f64) -> String {
+    let formatted = format!("{:.2}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "" | "-0" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+/// Uppercases a character when it has a single-character uppercase form.
+fn to_upper(ch: char) -> char {
+    let mut upper = ch.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => ch,
+    }
+}
+
+/// Rewrites one token of alternate dot and dash notation as ASCII Morse.
+fn canonical_token(token: &str) -> String {
+    if let Some(elements) = spoken_elements(token) {
+        return elements;
+    }
+
+    token
+        .chars()
+        .map(|c| match c {
+            '·' | '•' => '.',
+            '−' | '–' | '—' => '-',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Parses a token of spoken syllables such as `di-dah-dit`.
+fn spoken_elements(token: &str) -> Option<String> {
+    let lower = token.to_ascii_lowercase();
+    let mut rest = lower.as_str();
+    let mut elements = String::new();
+
+    while !rest.is_empty() {
+        rest = rest.trim_start_matches('-');
+        if let Some(next) = rest.strip_prefix("dah") {
+            elements.push('-');
+            rest = next;
+        } else if let Some(next) = rest.strip_prefix("dit") {
+            elements.push('.');
+            rest = next;
+        } else if let Some(next) = rest.strip_prefix("di") {
+            elements.push('.');
+            rest = next;
+        } else if !rest.is_empty() {
+            return None;
+        }
+    }
+
+    (!elements.is_empty()).then_some(elements)
+}
+
+/// English letters from most to least frequent.
+const LETTER_FREQUENCY: &str = "ETAOINSHRDLCUMWFGYPBVKJXQZ";
+
+/// Digits and the letters sent for them as cut numbers.
+const CUT_NUMBERS: &[(char, char)] = &[
+    ('1', 'A'),
+    ('2', 'U'),
+    ('3', 'V'),
+    ('5', 'E'),
+    ('7', 'B'),
+    ('8', 'D'),
+    ('9', 'N'),
+    ('0', 'T'),
+];
+
+/// Upper bound on the results of `decode_ambiguous`.
+const MAX_AMBIGUOUS_DECODINGS: usize = 64;
+
+/// Characters in the order the Koch method introduces them.
+#[cfg(feature = "std")]
+const KOCH_ORDER: &str = "KMURESNAPTLWI.JZ=FOY,VG5/Q92H38B?47C1D60X";
+
+/// Longest code, in elements, kept in the decode index.
+const MAX_INDEXED_ELEMENTS: usize = 9;
+
+/// Maps a code of dots and dashes to its slot in the decode index.
+///
+/// The elements are read as bits (dash = 1) below a leading 1 bit that
+/// marks the length. Codes that are empty, too long, or contain anything
+/// else have no slot.
+fn code_index(code: &str) -> Option<usize> {
+    if code.is_empty() || code.len() > MAX_INDEXED_ELEMENTS {
+        return None;
+    }
+
+    code.bytes().try_fold(1, |index, element| match element {
+        b'.' => Some(index << 1),
+        b'-' => Some(index << 1 | 1),
+        _ => None,
+    })
+}
+
+/// Ranks a character by how common it is, with non-letters last.
+fn frequency_rank(ch: char) -> usize {
+    LETTER_FREQUENCY.find(ch).unwrap_or(LETTER_FREQUENCY.len())
+}
+
+/// Audio nodes of a tone scheduled by [`AudioPlayer`].
+#[cfg(feature = "wasm")]
+struct ScheduledTone {
+    oscillator: OscillatorNode,
+    gain_node: GainNode,
+    end_time: f64,
+}
+
+#[cfg(feature = "wasm")]
+impl ScheduledTone {
+    /// Detaches the tone's nodes from the audio graph so they can be freed.
+    fn disconnect(&self) -> Result<(), JsValue> {
+        self.oscillator.disconnect()?;
+        self.gain_node.disconnect()
+    }
+}
+
+/// Disconnects and drops the tones at the front of the queue that ended by
+/// `time`.
+#[cfg(feature = "wasm")]
+fn release_finished(scheduled: &mut VecDeque<ScheduledTone>, time: f64) -> Result<(), JsValue> {
+    while let Some(tone) = scheduled.pop_front_if(|tone| tone.end_time <= time) {
+        tone.disconnect()?;
+    }
+
+    Ok(())
+}
+
+/// Checks that a tone pitch is within the supported 100-2000 Hz range.
+#[cfg(feature = "wasm")]
+fn check_frequency(hz: f64) -> Result<(), JsValue> {
+    if !(100.0..=2000.0).contains(&hz) {
+        return Err(JsValue::from_str(&format!(
+            "frequency {} Hz is outside the supported 100-2000 Hz range",
+            hz
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that a speed is within the supported 1-60 WPM range.
+///
+/// Zero or negative speeds would give infinite or negative element lengths.
+#[cfg(feature = "wasm")]
+fn check_wpm(wpm: f64) -> Result<(), JsValue> {
+    timing::check_wpm(wpm).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Returns the current Unix time in milliseconds.
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+fn now_ms() -> f64 {
+    js_sys::Date::now()
+}
+
+/// Returns the current Unix time in milliseconds.
+#[cfg(all(feature = "std", not(all(target_arch = "wasm32", feature = "wasm"))))]
+fn now_ms() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |elapsed| elapsed.as_secs_f64() * 1000.0)
+}
+
+/// Lazy encoder behind [`MorseCodec::encode_iter`].
+struct EncodeIter<'a> {
+    codec: &'a MorseCodec,
+    rest: &'a str,
+    upper: Option<core::char::ToUppercase>,
+    mark: Option<char>,
+}
+
+impl EncodeIter<'_> {
+    /// Consumes a combining mark following `ch`, returning the letter
+    /// they compose to.
+    fn take_combining_mark(&mut self, ch: char) -> Option<char> {
+        let mark = self.rest.chars().next()?;
+        let composed = alphabet::compose_mark(to_upper(ch), mark)?;
+        self.rest = &self.rest[mark.len_utf8()..];
+        Some(composed)
+    }
+}
+
+impl<'a> Iterator for EncodeIter<'a> {
+    type Item = (char, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ch = match self.mark.take() {
+                Some(mark) => mark,
+                None => match self.upper.as_mut().and_then(Iterator::next) {
+                    Some(upper) => {
+                        let (base, mark) = self.codec.alphabet.decompose(upper);
+                        self.mark = mark;
+                        base
+                    }
+                    None => {
+                        if let Some((code, len)) = prosign::parse(self.rest) {
+                            self.rest = &self.rest[len..];
+                            return Some(('<', code));
+                        }
+
+                        let mut next = self.rest.chars().next()?;
+                        self.rest = &self.rest[next.len_utf8()..];
+                        if let Some(composed) = self.take_combining_mark(next) {
+                            next = composed;
+                        }
+                        self.upper = Some(next.to_uppercase());
+                        continue;
+                    }
+                },
+            };
+
+            if let Some(morse) = self.codec.code_for(ch) {
+                return Some((ch, morse));
+            }
+        }
+    }
+}
+
+/// Direction chosen by [`MorseWave::smart_convert`].
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionDirection {
+    /// Input is plain text and gets encoded to Morse code.
+    Encode,
+    /// Input is Morse code and gets decoded to text.
+    Decode,
+}
+
+/// WebAssembly-compatible Morse code interface.
+///
+/// Provides WASM bindings for encoding, decoding, and validating Morse code
+/// in browser environments.
+///
+/// # Example
+///
+/// ```javascript
+/// import init, { MorseWave } from './pkg/morsewave.js';
+///
+/// await init();
+/// const morse = new MorseWave();
+/// console.log(morse.text_to_morse("HELLO"));
+/// ```
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub struct MorseWave {
+    codec: MorseCodec,
+    max_input_len: Option<usize>,
+    truncated: Cell<bool>,
+}
+
+#[cfg(feature = "wasm")]
+impl Default for MorseWave {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl MorseWave {
+    /// Creates a new MorseWave instance.
+    ///
+    /// Initializes panic hook for better error messages in browser console.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        console_error_panic_hook::set_once();
+        MorseWave {
+            codec: MorseCodec::new(),
+            max_input_len: None,
+            truncated: Cell::new(false),
+        }
+    }
+
+    /// Caps the input length accepted by the conversion methods.
+    ///
+    /// Protects a public-facing page from pathological input. Longer input
+    /// to [`text_to_morse`](Self::text_to_morse) and
+    /// [`morse_to_text`](Self::morse_to_text) is truncated, and
+    /// [`was_truncated`](Self::was_truncated) reports that it happened.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_len` - Maximum input length in characters (`None`, the
+    ///   default, for unlimited)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseWave;
+    /// let mut morse = MorseWave::new();
+    ///
+    /// morse.set_max_input_len(Some(2));
+    /// assert_eq!(morse.text_to_morse("SOS"), "... ---");
+    /// assert!(morse.was_truncated());
+    ///
+    /// morse.set_max_input_len(Some(5));
+    /// assert_eq!(morse.morse_to_text("... --- ..."), "S");
+    /// assert_eq!(morse.text_to_morse("HI"), ".... ..");
+    /// assert!(!morse.was_truncated());
+    /// ```
+    pub fn set_max_input_len(&mut self, max_len: Option<usize>) {
+        self.max_input_len = max_len;
+    }
+
+    /// Returns whether the input of the most recent conversion was
+    /// truncated by the [`set_max_input_len`](Self::set_max_input_len) cap.
+    pub fn was_truncated(&self) -> bool {
+        self.truncated.get()
+    }
+
+    /// Converts text to Morse code.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text to encode, truncated to the configured maximum length
+    ///
+    /// # Returns
+    ///
+    /// Morse code string
+    pub fn text_to_morse(&self, text: &str) -> String {
+        self.codec.encode(self.limit(text))
+    }
+
+    /// Converts text to Morse code, keeping unknown characters visible.
+    ///
+    /// Characters with a Morse mapping are encoded as usual, including
+    /// combining accents and `<AR>` prosign notation. Any other character
+    /// is emitted literally as its own token wrapped in square brackets,
+    /// e.g. `[😀]`. Since brackets are never part of Morse code, a decoder
+    /// can recover the character from any token of the form `[c]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text to encode, truncated to the configured maximum length
+    ///
+    /// # Returns
+    ///
+    /// Morse code string with bracketed literal characters
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseWave;
+    /// let morse = MorseWave::new();
+    /// assert_eq!(morse.text_to_morse_lossless("Hi 😀"), ".... .. / [😀]");
+    /// assert_eq!(morse.text_to_morse_lossless("e\u{301} <AR>"), "..-.. / .-.-.");
+    /// ```
+    pub fn text_to_morse_lossless(&self, text: &str) -> String {
+        self.codec
+            .encode_substituting(self.limit(text), |ch| format!("[{}]", ch))
+    }
+
+    /// Converts Morse code to text.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code to decode, truncated to the configured maximum
+    ///   length (a token cut off by the limit is dropped)
+    ///
+    /// # Returns
+    ///
+    /// Decoded text string
+    pub fn morse_to_text(&self, morse: &str) -> String {
+        let mut limited = self.limit(morse);
+        if self.truncated.get() && !morse[limited.len()..].starts_with(char::is_whitespace) {
+            limited = limited.rfind(' ').map_or("", |end| &limited[..end]);
+        }
+        self.codec.decode(limited)
+    }
+
+    /// Serializes a batch of messages to JSON.
+    ///
+    /// Useful for persisting conversion history, e.g. in `localStorage`.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - Messages to serialize
+    ///
+    /// # Returns
+    ///
+    /// JSON array of message objects
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{MorseMessage, MorseWave};
+    /// let morse = MorseWave::new();
+    ///
+    /// let messages = vec![
+    ///     MorseMessage::new("SOS".into(), "... --- ...".into(), 1.0),
+    ///     MorseMessage::new("HI".into(), ".... ..".into(), 2.0),
+    /// ];
+    /// let json = morse.messages_to_json(messages.clone());
+    /// assert_eq!(morse.messages_from_json(&json).unwrap(), messages);
+    /// assert_eq!(morse.messages_to_json(Vec::new()), "[]");
+    /// ```
+    pub fn messages_to_json(&self, messages: Vec<MorseMessage>) -> String {
+        serde_json::to_string(&messages).unwrap_or_default()
+    }
+
+    /// Deserializes a batch of messages from JSON.
+    ///
+    /// Accepts the output of [`messages_to_json`](Self::messages_to_json).
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - JSON array of message objects
+    ///
+    /// # Returns
+    ///
+    /// The parsed messages, or a JsValue error describing invalid JSON
+    pub fn messages_from_json(&self, json: &str) -> Result<Vec<MorseMessage>, JsValue> {
+        serde_json::from_str(json).map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// Lists every supported character as JSON.
+    ///
+    /// Lets front-ends render a reference chart without hardcoding the
+    /// alphabet.
+    ///
+    /// # Returns
+    ///
+    /// JSON array of `[char, morse]` pairs sorted by character
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseWave;
+    /// let morse = MorseWave::new();
+    /// assert!(morse.table_json().starts_with(r#"[[" ","/"],["!","#));
+    /// ```
+    pub fn table_json(&self) -> String {
+        serde_json::to_string(&self.codec.table()).unwrap_or_default()
+    }
+
+    /// Detects whether input should be encoded or decoded.
+    ///
+    /// Uses [`MorseCodec::looks_like_morse`] to tell Morse code from text.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Text or Morse code
+    ///
+    /// # Returns
+    ///
+    /// `Decode` for Morse code input, `Encode` otherwise
+    pub fn detect_direction(&self, input: &str) -> ConversionDirection {
+        if self.codec.looks_like_morse(input) {
+            ConversionDirection::Decode
+        } else {
+            ConversionDirection::Encode
+        }
+    }
+
+    /// Converts input to the opposite representation.
+    ///
+    /// Decodes input that looks like Morse code and encodes anything else,
+    /// so a single text box can convert in either direction. Call
+    /// [`detect_direction`](Self::detect_direction) to find out which way
+    /// the input will go.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Text or Morse code
+    ///
+    /// # Returns
+    ///
+    /// Morse code for text input, or text for Morse code input
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::{ConversionDirection, MorseWave};
+    /// let morse = MorseWave::new();
+    ///
+    /// assert_eq!(morse.smart_convert("SOS"), "... --- ...");
+    /// assert_eq!(morse.smart_convert("... --- ..."), "SOS");
+    /// assert_eq!(morse.detect_direction("... --- ..."), ConversionDirection::Decode);
+    /// ```
+    pub fn smart_convert(&self, input: &str) -> String {
+        match self.detect_direction(input) {
+            ConversionDirection::Encode => self.text_to_morse(input),
+            ConversionDirection::Decode => self.morse_to_text(input),
+        }
+    }
+
+    /// Validates Morse code syntax.
+    ///
+    /// Checks if input contains only valid Morse characters (., -, /, space).
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code to validate
+    ///
+    /// # Returns
+    ///
+    /// true if valid, false otherwise
+    pub fn validate_morse(&self, morse: &str) -> bool {
+        morse
+            .split_whitespace()
+            .all(|code| code.chars().all(|c| c == '.' || c == '-' || c == '/'))
+    }
+
+    /// Validates that Morse code decodes completely.
+    ///
+    /// Stricter than [`validate_morse`](Self::validate_morse): besides the
+    /// syntax check, every space-separated token must be the code of a known
+    /// character or the word separator, so input like `"........"` is
+    /// rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code to validate
+    ///
+    /// # Returns
+    ///
+    /// true if every token decodes, false otherwise
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseWave;
+    /// let morse = MorseWave::new();
+    ///
+    /// assert!(morse.validate_decodable("... --- ... / .. "));
+    /// assert!(morse.validate_morse("........"));
+    /// assert!(!morse.validate_decodable("........"));
+    /// ```
+    pub fn validate_decodable(&self, morse: &str) -> bool {
+        self.validate_morse(morse)
+            && morse
+                .split_whitespace()
+                .all(|code| self.codec.decode_map.contains_key(code))
+    }
+
+    /// Finds the first invalid character in Morse code.
+    ///
+    /// Uses the same rules as [`validate_morse`](Self::validate_morse), but
+    /// reports where validation failed so editors can highlight it.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code to validate
+    ///
+    /// # Returns
+    ///
+    /// Byte index of the first invalid character, or `None` if valid
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseWave;
+    /// let morse = MorseWave::new();
+    /// assert_eq!(morse.validate_morse_detailed(""), None);
+    /// assert_eq!(morse.validate_morse_detailed("... / ---"), None);
+    /// assert_eq!(morse.validate_morse_detailed("... -*"), Some(5));
+    /// ```
+    pub fn validate_morse_detailed(&self, morse: &str) -> Option<usize> {
+        morse
+            .char_indices()
+            .find(|&(_, c)| !(c == '.' || c == '-' || c == '/' || c.is_whitespace()))
+            .map(|(index, _)| index)
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl MorseWave {
+    /// Cuts input down to the configured maximum length, recording whether
+    /// anything was removed.
+    fn limit<'a>(&self, input: &'a str) -> &'a str {
+        let end = self
+            .max_input_len
+            .and_then(|max_len| input.char_indices().nth(max_len))
+            .map(|(end, _)| end);
+        self.truncated.set(end.is_some());
+        end.map_or(input, |end| &input[..end])
     }
 }
 
@@ -302,38 +4094,85 @@ impl MorseWave {
 /// const player = new AudioPlayer(20); // 20 WPM
 /// player.play_morse("... --- ...");
 /// ```
+#[cfg(feature = "wasm")]
 #[wasm_bindgen]
 pub struct AudioPlayer {
     context: AudioContext,
-    dot_duration: f64,
+    codec: MorseCodec,
+    timing: MorseTiming,
+    frequency: f64,
+    dash_frequency: Option<f64>,
+    flag_frequency: f64,
+    volume: f64,
+    fist: Option<FistProfile>,
+    rise_time_ms: f64,
+    wave_type: OscillatorType,
+    // Tones in scheduling order, shared with the `onended` handler that
+    // releases each message's nodes once it has finished.
+    scheduled: Rc<RefCell<VecDeque<ScheduledTone>>>,
 }
 
+#[cfg(feature = "wasm")]
 #[wasm_bindgen]
 impl AudioPlayer {
     /// Creates a new AudioPlayer with specified speed.
     ///
     /// # Arguments
     ///
-    /// * `wpm` - Words per minute (5-40 recommended)
+    /// * `wpm` - Words per minute (1-60, 5-40 recommended)
     ///
     /// # Returns
     ///
-    /// Result containing AudioPlayer or JsValue error
+    /// Result containing AudioPlayer, or a JsValue error if `wpm` is out of
+    /// range or no AudioContext is available
     ///
     /// # Formula
     ///
     /// dot_duration_ms = 1200 / WPM
     #[wasm_bindgen(constructor)]
     pub fn new(wpm: f64) -> Result<AudioPlayer, JsValue> {
+        check_wpm(wpm)?;
         let context = AudioContext::new()?;
-        let dot_duration = 1200.0 / wpm;
 
         Ok(AudioPlayer {
             context,
-            dot_duration,
+            codec: MorseCodec::new(),
+            timing: MorseTiming::new(wpm),
+            frequency: 800.0,
+            dash_frequency: None,
+            flag_frequency: 1200.0,
+            fist: None,
+            volume: 0.3,
+            rise_time_ms: 5.0,
+            wave_type: OscillatorType::Sine,
+            scheduled: Rc::new(RefCell::new(VecDeque::new())),
         })
     }
 
+    /// Creates a new AudioPlayer using Farnsworth timing.
+    ///
+    /// Characters are sent at `char_wpm` while the gaps between characters
+    /// and words are stretched so the overall speed is `effective_wpm`.
+    /// If `effective_wpm` is not slower than `char_wpm`, standard timing is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `char_wpm` - Speed at which individual characters are sent (1-60)
+    /// * `effective_wpm` - Overall speed including the stretched gaps (1-60)
+    ///
+    /// # Returns
+    ///
+    /// Result containing AudioPlayer, or a JsValue error if either speed is
+    /// out of range or no AudioContext is available
+    ///
+    /// See [`MorseTiming::farnsworth`] for the spacing formula.
+    pub fn with_farnsworth(char_wpm: f64, effective_wpm: f64) -> Result<AudioPlayer, JsValue> {
+        check_wpm(effective_wpm)?;
+        let mut player = AudioPlayer::new(char_wpm)?;
+        player.timing = MorseTiming::farnsworth(char_wpm, effective_wpm);
+        Ok(player)
+    }
+
     /// Plays Morse code audio.
     ///
     /// Synthesizes audio for dots, dashes, and spaces with proper timing.
@@ -349,36 +4188,312 @@ impl AudioPlayer {
     /// # Timing
     ///
     /// * Dot: 1 unit
-    /// * Dash: 3 units
+    /// * Dash: 3 units, or the configured weight
     /// * Gap between elements: 1 unit
-    /// * Gap between letters: 3 units (space)
-    /// * Gap between words: 7 units (/)
+    /// * Gap between letters: 3 units in total (space), or the configured
+    ///   letter gap
+    /// * Gap between words: 7 units in total (/), or the configured word gap
+    ///
+    /// With Farnsworth timing, letter and word gaps use the stretched
+    /// spacing unit instead of the dot duration.
     pub fn play_morse(&self, morse: &str) -> Result<(), JsValue> {
+        self.schedule_morse(morse, &[], self.context.current_time())?;
+        Ok(())
+    }
+
+    /// Plays Morse code audio with some letters at a distinct pitch.
+    ///
+    /// Flagged letters, such as prosigns or letters the user got wrong in a
+    /// drill, are played at the flag frequency so the listener notices them.
+    /// Everything else sounds as in [`play_morse`](Self::play_morse).
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to play
+    /// * `flagged` - Zero-based indices of the letters to flag, counting
+    ///   letters only (word separators are skipped)
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success or error
+    ///
+    /// # Example
+    ///
+    /// ```javascript
+    /// // Sound the final <AR> at the flag pitch.
+    /// player.play_morse_flagged("-.-. --.- .-.-.", [2]);
+    /// ```
+    pub fn play_morse_flagged(&self, morse: &str, flagged: Vec<u32>) -> Result<(), JsValue> {
+        self.schedule_morse(morse, &flagged, self.context.current_time())?;
+        Ok(())
+    }
+
+    /// Encodes text and plays it as Morse code audio.
+    ///
+    /// Uses the player's own International Morse codec, so encoding always
+    /// matches what [`play_morse`](Self::play_morse) expects.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text to encode and play
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success or error
+    pub fn play_text(&self, text: &str) -> Result<(), JsValue> {
+        self.play_morse(&self.codec.encode(text))
+    }
+
+    /// Plays several Morse messages back-to-back.
+    ///
+    /// Each message uses the same timing as [`play_morse`](Self::play_morse)
+    /// and is followed by `gap_ms` of silence before the next one starts,
+    /// which suits automated beacon transmissions.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - Morse code strings to play in order
+    /// * `gap_ms` - Silence between messages in milliseconds
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success or error
+    ///
+    /// # Example
+    ///
+    /// ```javascript
+    /// player.play_sequence(["-.-. --.-", "-.. .", "-.- -.-"], 2000);
+    /// ```
+    pub fn play_sequence(&self, messages: Vec<String>, gap_ms: f64) -> Result<(), JsValue> {
         let mut time = self.context.current_time();
 
-        for ch in morse.chars() {
-            match ch {
-                '.' => {
-                    self.play_tone(time, self.dot_duration)?;
-                    time += self.dot_duration / 1000.0;
-                    time += self.dot_duration / 1000.0;
-                }
-                '-' => {
-                    self.play_tone(time, self.dot_duration * 3.0)?;
-                    time += (self.dot_duration * 3.0) / 1000.0;
-                    time += self.dot_duration / 1000.0;
-                }
-                ' ' => {
-                    time += (self.dot_duration * 3.0) / 1000.0;
-                }
-                '/' => {
-                    time += (self.dot_duration * 7.0) / 1000.0;
+        for morse in &messages {
+            let end = self.schedule_morse(morse, &[], time)?;
+            time = end + gap_ms.max(0.0) / 1000.0;
+        }
+
+        Ok(())
+    }
+
+    /// Plays Morse code audio and returns a Promise that resolves when
+    /// playback finishes.
+    ///
+    /// The Promise is resolved by a `setTimeout` scheduled for the playback
+    /// duration, so callers can `await` it to sequence UI state. Empty
+    /// input resolves immediately, and scheduling errors reject the Promise.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to play
+    ///
+    /// # Example
+    ///
+    /// ```javascript
+    /// button.disabled = true;
+    /// await player.play_morse_async("... --- ...");
+    /// button.disabled = false;
+    /// ```
+    pub fn play_morse_async(&self, morse: &str) -> js_sys::Promise {
+        if let Err(err) = self.play_morse(morse) {
+            return js_sys::Promise::reject(&err);
+        }
+
+        let duration = self.duration_ms(morse);
+        if duration <= 0.0 {
+            return js_sys::Promise::resolve(&JsValue::UNDEFINED);
+        }
+
+        js_sys::Promise::new(&mut |resolve, reject| {
+            let scheduled = web_sys::window()
+                .ok_or_else(|| JsValue::from_str("no global window available"))
+                .and_then(|window| {
+                    window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                        &resolve,
+                        duration.ceil() as i32,
+                    )
+                });
+
+            if let Err(err) = scheduled {
+                let _ = reject.call1(&JsValue::UNDEFINED, &err);
+            }
+        })
+    }
+
+    /// Computes how long a Morse string takes to play.
+    ///
+    /// Walks the same timing rules as [`play_morse`](Self::play_morse)
+    /// without scheduling any audio, so UIs can show progress up front.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to measure
+    ///
+    /// # Returns
+    ///
+    /// Total duration in milliseconds
+    pub fn duration_ms(&self, morse: &str) -> f64 {
+        self.timing.duration_ms(morse)
+    }
+
+    /// Computes the keying timeline of a Morse string.
+    ///
+    /// Describes every tone and silence span exactly as
+    /// [`play_morse`](Self::play_morse) schedules them, including letter and
+    /// word gaps, so visualizers can stay in sync with playback.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to lay out
+    ///
+    /// # Returns
+    ///
+    /// Tone (`on: true`) and silence (`on: false`) events in order, with
+    /// each tone's `frequency` set to the pitch it is played at
+    pub fn timeline(&self, morse: &str) -> Vec<ToneEvent> {
+        self.timeline_with_flags(morse, &[])
+    }
+
+    /// Computes the keying timeline with some letters at the flag pitch.
+    ///
+    /// Matches what [`play_morse_flagged`](Self::play_morse_flagged)
+    /// schedules, so visualizers can highlight the flagged letters too.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to lay out
+    /// * `flagged` - Zero-based indices of the letters to flag
+    ///
+    /// # Returns
+    ///
+    /// Tone and silence events in order, with flagged tones carrying the
+    /// flag frequency
+    pub fn flagged_timeline(&self, morse: &str, flagged: Vec<u32>) -> Vec<ToneEvent> {
+        self.timeline_with_flags(morse, &flagged)
+    }
+
+    /// Lays out the timeline, assigning each tone the pitch it plays at.
+    fn timeline_with_flags(&self, morse: &str, flagged: &[u32]) -> Vec<ToneEvent> {
+        let mut events = self.timing.timeline(morse);
+        // The letter each element belongs to, in the order the tones play.
+        let letters = morse
+            .split_whitespace()
+            .filter(|token| token.contains(['.', '-']))
+            .enumerate()
+            .flat_map(|(letter, token)| {
+                token
+                    .chars()
+                    .filter(|&c| c == '.' || c == '-')
+                    .map(move |_| letter)
+            });
+        let tones = events.iter_mut().filter(|event| event.on);
+
+        for (event, letter) in tones.zip(letters) {
+            // Dashes are at least 2.5 dots long (2.0 with full jitter),
+            // while jittered dots stay under 1.2.
+            let dash = event.duration_ms > self.timing.dot_duration() * 1.5;
+            let flag = u32::try_from(letter).is_ok_and(|letter| flagged.contains(&letter));
+            event.frequency = Some(match self.dash_frequency {
+                _ if flag => self.flag_frequency,
+                Some(hz) if dash => hz,
+                _ => self.frequency,
+            });
+        }
+
+        events
+    }
+
+    /// Computes the keying timeline with each tone's envelope split out.
+    ///
+    /// Like [`timeline`](Self::timeline), but every tone is split into its
+    /// rise, steady and fall segments, with `ramp: true` on the rise and
+    /// fall, so spectrogram previews can draw the envelope. Segments of zero
+    /// length are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to lay out
+    ///
+    /// # Returns
+    ///
+    /// Tone segments and silence events in order
+    pub fn envelope_timeline(&self, morse: &str) -> Vec<ToneEvent> {
+        let mut events = Vec::new();
+
+        for event in self.timeline(morse) {
+            if !event.on {
+                events.push(event);
+                continue;
+            }
+
+            let rise = self.rise_time_ms.min(event.duration_ms / 2.0);
+            let segments = [
+                (event.start_ms, rise, true),
+                (event.start_ms + rise, event.duration_ms - 2.0 * rise, false),
+                (event.end_ms() - rise, rise, true),
+            ];
+            for (start_ms, duration_ms, ramp) in segments {
+                if duration_ms > 0.0 {
+                    events.push(ToneEvent {
+                        start_ms,
+                        duration_ms,
+                        ramp,
+                        ..event
+                    });
                 }
-                _ => {}
             }
         }
 
-        Ok(())
+        events
+    }
+
+    /// Schedules the tones of a Morse string starting at `start_time`.
+    ///
+    /// Returns the AudioContext time at which the transmission ends.
+    fn schedule_morse(
+        &self,
+        morse: &str,
+        flagged: &[u32],
+        start_time: f64,
+    ) -> Result<f64, JsValue> {
+        let mut end_ms = 0.0;
+        let mut played = false;
+        let mut gains = self.fist.iter().flat_map(|fist| fist.gains(morse));
+
+        for event in self.timeline_with_flags(morse, flagged) {
+            if let Some(frequency) = event.frequency {
+                self.play_tone(
+                    start_time + event.start_ms / 1000.0,
+                    event.duration_ms,
+                    frequency,
+                    gains.next().unwrap_or(1.0),
+                )?;
+                played = true;
+            }
+            end_ms = event.end_ms();
+        }
+
+        if played {
+            self.release_when_ended();
+        }
+
+        Ok(start_time + end_ms / 1000.0)
+    }
+
+    /// Releases the nodes of every tone up to the last one scheduled once
+    /// that tone has ended, so a finished message does not keep its nodes
+    /// until the next play or stop.
+    fn release_when_ended(&self) {
+        let scheduled = self.scheduled.borrow();
+        let Some(last) = scheduled.back() else {
+            return;
+        };
+
+        let queue = Rc::clone(&self.scheduled);
+        let end_time = last.end_time;
+        let release = Closure::once_into_js(move || {
+            let _ = release_finished(&mut queue.borrow_mut(), end_time);
+        });
+        last.oscillator.set_onended(Some(release.unchecked_ref()));
     }
 
     /// Plays a single tone at specified time and duration.
@@ -387,38 +4502,292 @@ impl AudioPlayer {
     ///
     /// * `start_time` - AudioContext time to start
     /// * `duration` - Duration in milliseconds
+    /// * `frequency` - Tone pitch in Hz, from the timeline (the configured
+    ///   pitch, the dash frequency for dashes in two-tone mode, or the
+    ///   flag frequency for flagged letters)
+    /// * `gain` - Multiplier applied to the volume, from the fist profile
     ///
     /// # Audio Properties
     ///
-    /// * Frequency: 800 Hz sine wave
-    /// * Volume: 0.3 gain
-    fn play_tone(&self, start_time: f64, duration: f64) -> Result<(), JsValue> {
+    /// * Waveform: configured oscillator type (sine default)
+    /// * Volume: configured gain (0.3 default), scaled by `gain`
+    /// * Envelope: linear rise and fall of `rise_time_ms` (5 ms default),
+    ///   shortened to half the tone for very short elements
+    fn play_tone(
+        &self,
+        start_time: f64,
+        duration: f64,
+        frequency: f64,
+        gain: f64,
+    ) -> Result<(), JsValue> {
         let oscillator = self.context.create_oscillator()?;
         let gain_node = self.context.create_gain()?;
 
-        oscillator.set_type(web_sys::OscillatorType::Sine);
+        oscillator.set_type(self.wave_type);
 
         let freq_param = oscillator.frequency();
-        freq_param.set_value(800.0);
+        freq_param.set_value(frequency as f32);
+
+        let end_time = start_time + duration / 1000.0;
+        let rise_time = self.rise_time_ms.min(duration / 2.0) / 1000.0;
+        let volume = (self.volume * gain) as f32;
 
         let gain_param = gain_node.gain();
-        gain_param.set_value(0.3);
+        gain_param.set_value_at_time(0.0, start_time)?;
+        gain_param.linear_ramp_to_value_at_time(volume, start_time + rise_time)?;
+        gain_param.set_value_at_time(volume, end_time - rise_time)?;
+        gain_param.linear_ramp_to_value_at_time(0.0, end_time)?;
 
         oscillator.connect_with_audio_node(&gain_node)?;
         gain_node.connect_with_audio_node(&self.context.destination())?;
 
         oscillator.start_with_when(start_time)?;
-        oscillator.stop_with_when(start_time + duration / 1000.0)?;
+        oscillator.stop_with_when(end_time)?;
+
+        // Node lifecycle: each tone gets its own oscillator -> gain ->
+        // destination chain, since an oscillator can only be started once.
+        // The chain is queued until the tone has ended and is then
+        // disconnected here, by the message's final `onended`, or in
+        // `stop`, so the graph holds only the tones still pending instead
+        // of every tone ever played. Tones are queued in start order, so
+        // only the front of the queue needs checking.
+        let mut scheduled = self.scheduled.borrow_mut();
+        let result = release_finished(&mut scheduled, self.context.current_time());
+        scheduled.push_back(ScheduledTone {
+            oscillator,
+            gain_node,
+            end_time,
+        });
+
+        result
+    }
+
+    /// Stops playback immediately.
+    ///
+    /// Cancels every tone that is sounding or scheduled to sound, so a long
+    /// transmission can be interrupted from a "Stop" button.
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success or error
+    pub fn stop(&self) -> Result<(), JsValue> {
+        for tone in self.scheduled.borrow_mut().drain(..) {
+            tone.oscillator.stop()?;
+            tone.disconnect()?;
+        }
 
         Ok(())
     }
 
     /// Updates playback speed.
     ///
+    /// Resets any Farnsworth spacing back to standard timing.
+    ///
+    /// # Arguments
+    ///
+    /// * `wpm` - New words per minute speed (1-60)
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success, or an error if `wpm` is out of range
+    pub fn set_wpm(&mut self, wpm: f64) -> Result<(), JsValue> {
+        check_wpm(wpm)?;
+        self.timing.set_wpm(wpm);
+        Ok(())
+    }
+
+    /// Updates the dash-to-dot ratio (weighting).
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - Dash length in dot units, clamped to 2.5-4.0 (3.0 default)
+    pub fn set_weight(&mut self, weight: f64) {
+        self.timing.set_weight(weight);
+    }
+
+    /// Updates the length of the gap between letters (space).
+    ///
+    /// Applies to playback as well as [`duration_ms`](Self::duration_ms)
+    /// and [`timeline`](Self::timeline), so a "letter spacing" control can
+    /// be offered independently of the character speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - Letter gap in spacing units, at least 1 (3.0 default)
+    pub fn set_letter_gap_units(&mut self, units: f64) {
+        self.timing.set_letter_gap_units(units);
+    }
+
+    /// Returns the length of the gap between letters, in spacing units.
+    pub fn letter_gap_units(&self) -> f64 {
+        self.timing.letter_gap_units()
+    }
+
+    /// Updates the length of the gap between words ("/").
+    ///
+    /// Applies to playback as well as [`duration_ms`](Self::duration_ms)
+    /// and [`timeline`](Self::timeline).
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - Word gap in spacing units, at least 1 (7.0 default)
+    pub fn set_word_gap_units(&mut self, units: f64) {
+        self.timing.set_word_gap_units(units);
+    }
+
+    /// Returns the length of the gap between words, in spacing units.
+    pub fn word_gap_units(&self) -> f64 {
+        self.timing.word_gap_units()
+    }
+
+    /// Updates the tone frequency.
+    ///
+    /// # Arguments
+    ///
+    /// * `hz` - Tone pitch in hertz (100-2000)
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success, or an error if `hz` is out of range
+    pub fn set_frequency(&mut self, hz: f64) -> Result<(), JsValue> {
+        check_frequency(hz)?;
+        self.frequency = hz;
+        Ok(())
+    }
+
+    /// Enables or disables two-tone mode.
+    ///
+    /// When set, dashes are played at `hz` while dots keep the base
+    /// frequency, which helps some learners tell the elements apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `hz` - Dash pitch in hertz (100-2000), or `None` to play dashes at
+    ///   the base frequency
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success, or an error if `hz` is out of range
+    pub fn set_dash_frequency(&mut self, hz: Option<f64>) -> Result<(), JsValue> {
+        if let Some(hz) = hz {
+            check_frequency(hz)?;
+        }
+        self.dash_frequency = hz;
+        Ok(())
+    }
+
+    /// Updates the pitch of flagged letters.
+    ///
+    /// # Arguments
+    ///
+    /// * `hz` - Pitch in hertz (100-2000) used by
+    ///   [`play_morse_flagged`](Self::play_morse_flagged) (1200 Hz default)
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success, or an error if `hz` is out of range
+    pub fn set_flag_frequency(&mut self, hz: f64) -> Result<(), JsValue> {
+        check_frequency(hz)?;
+        self.flag_frequency = hz;
+        Ok(())
+    }
+
+    /// Updates the tone volume.
+    ///
+    /// Negative values are clamped to silence.
+    ///
+    /// # Arguments
+    ///
+    /// * `volume` - Gain between 0.0 and 1.0
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success, or an error if `volume` exceeds 1.0
+    pub fn set_volume(&mut self, volume: f64) -> Result<(), JsValue> {
+        if volume > 1.0 {
+            return Err(JsValue::from_str(&format!(
+                "volume {} exceeds the maximum of 1.0",
+                volume
+            )));
+        }
+
+        self.volume = volume.max(0.0);
+        Ok(())
+    }
+
+    /// Updates the attack/release ramp time applied to each tone.
+    ///
+    /// Shaping the envelope removes the audible clicks caused by starting
+    /// and stopping tones abruptly. Negative values are clamped to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `ms` - Ramp duration in milliseconds
+    pub fn set_rise_time_ms(&mut self, ms: f64) {
+        self.rise_time_ms = ms.max(0.0);
+    }
+
+    /// Returns the attack/release ramp time applied to each tone, in
+    /// milliseconds.
+    pub fn rise_time_ms(&self) -> f64 {
+        self.rise_time_ms
+    }
+
+    /// Updates the oscillator waveform.
+    ///
+    /// A triangle wave sounds softer than the default sine, while a square
+    /// wave gives a buzzier sidetone.
+    ///
+    /// # Arguments
+    ///
+    /// * `wave_type` - Oscillator type for subsequent tones (`Sine` default)
+    pub fn set_wave_type(&mut self, wave_type: OscillatorType) {
+        self.wave_type = wave_type;
+    }
+
+    /// Returns the speed at which individual characters are sent.
+    pub fn character_wpm(&self) -> f64 {
+        self.timing.character_wpm()
+    }
+
+    /// Enables or disables hand-sent timing variation.
+    ///
+    /// Each element and gap is perturbed by up to `jitter` of its length,
+    /// so practice audio sounds like a straight key rather than a machine.
+    ///
+    /// # Arguments
+    ///
+    /// * `jitter` - Maximum variation as a fraction, clamped to 0.0-0.2
+    ///   (`None` for machine-perfect timing)
+    /// * `seed` - Seed for reproducible variation (`None` picks one at random)
+    pub fn set_humanize(&mut self, jitter: Option<f64>, seed: Option<u64>) {
+        self.timing.set_humanize(jitter, seed);
+    }
+
+    /// Enables or disables an operator-like loudness pattern.
+    ///
+    /// Each element is played slightly softer depending on its position in
+    /// the letter, following a pattern fixed by the seed, which imitates the
+    /// characteristic sending ("fist") of one operator. Off by default.
+    ///
     /// # Arguments
     ///
-    /// * `wpm` - New words per minute speed
-    pub fn set_wpm(&mut self, wpm: f64) {
-        self.dot_duration = 1200.0 / wpm;
+    /// * `depth` - Maximum softening as a fraction of the volume, clamped
+    ///   to 0.0-0.5 (`None` for an even volume)
+    /// * `seed` - Seed selecting the pattern (`None` picks one at random)
+    pub fn set_fist_profile(&mut self, depth: Option<f64>, seed: Option<u64>) {
+        self.fist = depth.map(|depth| FistProfile::new(depth, seed));
+    }
+
+    /// Measures one transmission of the standard word "PARIS ".
+    ///
+    /// At standard timing this equals 60000 / WPM, so it can be used to
+    /// check the speed setting.
+    ///
+    /// # Returns
+    ///
+    /// Duration in milliseconds
+    pub fn paris_word_duration_ms(&self) -> f64 {
+        self.timing.paris_word_duration_ms()
     }
 }
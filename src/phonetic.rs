@@ -0,0 +1,50 @@
+//! NATO phonetic alphabet for spoken readout.
+
+/// Letters and digits with their NATO phonetic words.
+const NATO: &[(char, &str)] = &[
+    ('A', "Alpha"),
+    ('B', "Bravo"),
+    ('C', "Charlie"),
+    ('D', "Delta"),
+    ('E', "Echo"),
+    ('F', "Foxtrot"),
+    ('G', "Golf"),
+    ('H', "Hotel"),
+    ('I', "India"),
+    ('J', "Juliet"),
+    ('K', "Kilo"),
+    ('L', "Lima"),
+    ('M', "Mike"),
+    ('N', "November"),
+    ('O', "Oscar"),
+    ('P', "Papa"),
+    ('Q', "Quebec"),
+    ('R', "Romeo"),
+    ('S', "Sierra"),
+    ('T', "Tango"),
+    ('U', "Uniform"),
+    ('V', "Victor"),
+    ('W', "Whiskey"),
+    ('X', "X-ray"),
+    ('Y', "Yankee"),
+    ('Z', "Zulu"),
+    ('0', "Zero"),
+    ('1', "One"),
+    ('2', "Two"),
+    ('3', "Three"),
+    ('4', "Four"),
+    ('5', "Five"),
+    ('6', "Six"),
+    ('7', "Seven"),
+    ('8', "Eight"),
+    ('9', "Nine"),
+];
+
+/// Returns the phonetic word for a letter or digit, matched
+/// case-insensitively.
+pub(crate) fn word(ch: char) -> Option<&'static str> {
+    let ch = ch.to_ascii_uppercase();
+    NATO.iter()
+        .find(|&&(letter, _)| letter == ch)
+        .map(|&(_, word)| word)
+}
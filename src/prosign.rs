@@ -0,0 +1,121 @@
+//! Procedural signals (prosigns) sent as run-together letters.
+
+use alloc::vec::Vec;
+
+/// Standard prosigns as (name, Morse) pairs.
+///
+/// Each code is the letters of the name joined without letter gaps.
+pub(crate) const PROSIGNS: &[(&str, &str)] = &[
+    ("AR", ".-.-."),
+    ("AS", ".-..."),
+    ("BK", "-...-.-"),
+    ("BT", "-...-"),
+    ("CL", "-.-..-.."),
+    ("CT", "-.-.-"),
+    ("HH", "........"),
+    ("KN", "-.--."),
+    ("SK", "...-.-"),
+    ("SN", "...-."),
+    ("SOS", "...---..."),
+];
+
+/// A standard procedural signal (prosign).
+///
+/// Prosigns are sent as their letters run together without letter gaps,
+/// e.g. SOS is `...---...` rather than `... --- ...`.
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::Prosign;
+///
+/// assert_eq!(Prosign::Sos.code(), "...---...");
+/// assert_eq!(Prosign::Kn.name(), "KN");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prosign {
+    /// End of message (AR).
+    Ar,
+    /// Wait (AS).
+    As,
+    /// Break, back to you (BK).
+    Bk,
+    /// Break between sections, also `=` (BT).
+    Bt,
+    /// Closing station (CL).
+    Cl,
+    /// Start of transmission (CT).
+    Ct,
+    /// Error, eight dots (HH).
+    Hh,
+    /// Go ahead, named station only (KN).
+    Kn,
+    /// End of contact (SK).
+    Sk,
+    /// Understood (SN).
+    Sn,
+    /// Distress signal (SOS).
+    Sos,
+}
+
+impl Prosign {
+    /// Returns the run-together Morse code of the prosign.
+    pub fn code(self) -> &'static str {
+        // Variants are declared in the same order as `PROSIGNS`.
+        PROSIGNS[self as usize].1
+    }
+
+    /// Returns the letters that make up the prosign, e.g. `"AR"`.
+    pub fn name(self) -> &'static str {
+        PROSIGNS[self as usize].0
+    }
+}
+
+/// Returns the prosign name for a Morse code, if any.
+pub(crate) fn name(code: &str) -> Option<&'static str> {
+    PROSIGNS
+        .iter()
+        .find(|&&(_, morse)| morse == code)
+        .map(|&(name, _)| name)
+}
+
+/// Parses a known prosign in `<NAME>` notation at the start of `text`.
+///
+/// Returns the prosign's Morse code and the number of bytes consumed.
+/// Names are matched case-insensitively.
+pub(crate) fn parse(text: &str) -> Option<(&'static str, usize)> {
+    let inner = text.strip_prefix('<')?;
+    // Names are at most three letters, so don't scan further for '>'.
+    let (end, _) = inner.char_indices().take(4).find(|&(_, c)| c == '>')?;
+    let name = &inner[..end];
+
+    PROSIGNS
+        .iter()
+        .find(|&&(known, _)| known.eq_ignore_ascii_case(name))
+        .map(|&(_, code)| (code, end + 2))
+}
+
+/// Prosigns that hand over or close a transmission in a two-way contact.
+const QSO: &[Prosign] = &[
+    Prosign::Ar,
+    Prosign::As,
+    Prosign::Bk,
+    Prosign::Cl,
+    Prosign::Kn,
+    Prosign::Sk,
+];
+
+/// Splits a run-together Morse token into QSO prosigns, if it consists of
+/// nothing else.
+pub(crate) fn split_qso(code: &str) -> Option<Vec<Prosign>> {
+    if code.is_empty() {
+        return Some(Vec::new());
+    }
+
+    QSO.iter().find_map(|&prosign| {
+        let rest = code.strip_prefix(prosign.code())?;
+        let mut prosigns = split_qso(rest)?;
+        prosigns.insert(0, prosign);
+        Some(prosigns)
+    })
+}
@@ -0,0 +1,67 @@
+//! RST signal reports (Readability, Strength, Tone).
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+/// Error returned when an RST component is out of range.
+///
+/// # Fields
+///
+/// * `component` - Which component was rejected: `'R'`, `'S'`, or `'T'`
+/// * `value` - The rejected value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RstError {
+    pub component: char,
+    pub value: u8,
+}
+
+impl fmt::Display for RstError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let max = if self.component == 'R' { 5 } else { 9 };
+        write!(
+            f,
+            "RST component {} is {}, expected 1-{}",
+            self.component, self.value, max
+        )
+    }
+}
+
+impl core::error::Error for RstError {}
+
+/// Builds a three-digit RST signal report.
+///
+/// # Arguments
+///
+/// * `readability` - Readability, 1 (unreadable) to 5 (perfectly readable)
+/// * `strength` - Signal strength, 1 (faint) to 9 (very strong)
+/// * `tone` - Tone quality, 1 (very rough) to 9 (pure)
+///
+/// # Returns
+///
+/// The report, e.g. `"599"`, or an `RstError` naming the first component
+/// out of range
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::rst;
+///
+/// assert_eq!(rst(5, 9, 9).unwrap(), "599");
+/// assert_eq!(rst(3, 5, 7).unwrap(), "357");
+///
+/// let err = rst(6, 9, 9).unwrap_err();
+/// assert_eq!((err.component, err.value), ('R', 6));
+/// assert!(rst(5, 0, 9).is_err());
+/// ```
+pub fn rst(readability: u8, strength: u8, tone: u8) -> Result<String, RstError> {
+    let components = [('R', readability, 5), ('S', strength, 9), ('T', tone, 9)];
+
+    for (component, value, max) in components {
+        if !(1..=max).contains(&value) {
+            return Err(RstError { component, value });
+        }
+    }
+
+    Ok(format!("{}{}{}", readability, strength, tone))
+}
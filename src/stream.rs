@@ -0,0 +1,255 @@
+//! Incremental encoding and decoding of input that arrives in chunks.
+
+use crate::{alphabet, to_upper, MorseCodec};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Decodes Morse code fed in arbitrary chunks, such as live keystrokes.
+///
+/// A token is decoded as soon as the space ending it arrives. A token
+/// still being typed is buffered across calls, so nothing is decoded twice
+/// and the whole input never has to be reprocessed. Tokens are split on
+/// spaces only, as in [`MorseCodec::decode`].
+///
+/// With American Morse a token may join the next one into a spaced code,
+/// and with Wabun a voicing mark combines with the kana before it, so the
+/// last completed token is held back until the one after it arrives. The
+/// concatenated output equals decoding the whole input at once.
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::{Alphabet, MorseCodec, MorseStreamDecoder};
+///
+/// let mut decoder = MorseStreamDecoder::new(MorseCodec::new());
+/// assert_eq!(decoder.push("... -"), "S");
+/// assert_eq!(decoder.push("-- ."), "O");
+/// assert_eq!(decoder.push(".. / .."), "S ");
+/// assert_eq!(decoder.flush(), "I");
+///
+/// // American C is ".. ." and Wabun ガ is カ followed by a voicing mark.
+/// for (alphabet, text) in [(Alphabet::American, "ICE CAT"), (Alphabet::Wabun, "ガイド")] {
+///     let codec = MorseCodec::with_alphabet(alphabet);
+///     let morse = codec.encode(text);
+///     let whole = codec.decode(&morse);
+///
+///     for split in 0..=morse.len() {
+///         let mut decoder = MorseStreamDecoder::new(MorseCodec::with_alphabet(alphabet));
+///         let mut chunked = decoder.push(&morse[..split]);
+///         chunked += &decoder.push(&morse[split..]);
+///         chunked += &decoder.flush();
+///         assert_eq!(chunked, whole);
+///     }
+/// }
+/// ```
+pub struct MorseStreamDecoder {
+    codec: MorseCodec,
+    pending: String,
+}
+
+impl MorseStreamDecoder {
+    /// Creates a stream decoder using the given codec's mappings.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The codec that decodes each completed token
+    pub fn new(codec: MorseCodec) -> Self {
+        MorseStreamDecoder {
+            codec,
+            pending: String::new(),
+        }
+    }
+
+    /// Feeds more Morse code to the decoder.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk` - The next piece of input, which may end mid-token
+    ///
+    /// # Returns
+    ///
+    /// Characters for the tokens completed by this chunk (possibly empty)
+    pub fn push(&mut self, chunk: &str) -> String {
+        self.pending.push_str(chunk);
+
+        let Some(end) = self.pending.rfind(' ') else {
+            return String::new();
+        };
+
+        let rest = self.pending.split_off(self.ready_len(end));
+        let text = self.codec.decode(&self.pending);
+        self.pending = rest;
+        text
+    }
+
+    /// Returns how much of the buffer can be decoded now, given that the
+    /// tokens before `end` are complete.
+    ///
+    /// The cut is always made at the start of a token, so decoding resumes
+    /// exactly where a decode of the whole input would.
+    fn ready_len(&self, end: usize) -> usize {
+        let alphabet = self.codec.alphabet;
+        if !alphabet.has_spaced_codes() && !alphabet.has_composed_marks() {
+            return end;
+        }
+
+        let spans: Vec<(usize, Option<char>)> = self
+            .codec
+            .code_spans(&self.pending[..end])
+            .map(|(start, code)| (start, self.codec.lookup(code)))
+            .collect();
+
+        // The last token may still join the next one or take a voicing
+        // mark, and a mark must stay with the character it composes into.
+        let mut held = spans.len().saturating_sub(1);
+        while let (Some((before, ch)), Some(next)) = (
+            spans[..held]
+                .iter()
+                .enumerate()
+                .rev()
+                .find_map(|(i, &(_, ch))| Some((i, ch?))),
+            spans[held..].iter().find_map(|&(_, ch)| ch),
+        ) {
+            if alphabet
+                .compose(String::from_iter([ch, next]))
+                .chars()
+                .count()
+                != 1
+            {
+                break;
+            }
+            held = before;
+        }
+
+        spans.get(held).map_or(end, |&(start, _)| start)
+    }
+
+    /// Decodes the buffered partial token, if any, as the end of input.
+    ///
+    /// # Returns
+    ///
+    /// The character for the final token (empty if nothing was buffered)
+    pub fn flush(&mut self) -> String {
+        let text = self.codec.decode(&self.pending);
+        self.pending.clear();
+        text
+    }
+
+    /// Returns the wrapped codec.
+    pub fn codec(&self) -> &MorseCodec {
+        &self.codec
+    }
+}
+
+/// Encodes text fed in arbitrary chunks, such as a large input piped
+/// through piece by piece.
+///
+/// Characters are encoded as soon as they can no longer be affected by
+/// later input. The last character is held back in case a combining accent
+/// follows in the next chunk, as is an unfinished `<NAME>` prosign. The
+/// concatenated output equals encoding the whole text at once.
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::{MorseCodec, MorseStreamEncoder};
+///
+/// let mut encoder = MorseStreamEncoder::new(MorseCodec::new());
+/// let mut morse = encoder.push("SO");
+/// assert_eq!(morse, "...");
+/// morse += &encoder.push("S <S");
+/// morse += &encoder.push("K> cafe");
+/// morse += &encoder.push("\u{301}");
+/// morse += &encoder.flush();
+///
+/// let codec = MorseCodec::new();
+/// assert_eq!(morse, codec.encode("SOS <SK> caf\u{e9}"));
+/// ```
+pub struct MorseStreamEncoder {
+    codec: MorseCodec,
+    pending: String,
+    started: bool,
+}
+
+impl MorseStreamEncoder {
+    /// Creates a stream encoder using the given codec's mappings.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The codec that encodes each completed character
+    pub fn new(codec: MorseCodec) -> Self {
+        MorseStreamEncoder {
+            codec,
+            pending: String::new(),
+            started: false,
+        }
+    }
+
+    /// Feeds more text to the encoder.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The next piece of input
+    ///
+    /// # Returns
+    ///
+    /// Morse code for the characters completed so far (possibly empty),
+    /// starting with a separating space if earlier output was produced
+    pub fn push(&mut self, text: &str) -> String {
+        self.pending.push_str(text);
+
+        // Hold back the last character in case a combining mark follows,
+        // unless it is itself a mark completing the character before it.
+        let mut tail = self.pending.char_indices().rev();
+        let mut ready_len = match (tail.next(), tail.next()) {
+            (Some((_, mark)), Some((_, base)))
+                if alphabet::compose_mark(to_upper(base), mark).is_some() =>
+            {
+                self.pending.len()
+            }
+            (Some((last, _)), _) => last,
+            (None, _) => 0,
+        };
+        if let Some(open) = self.pending.rfind('<') {
+            let name = &self.pending[open + 1..];
+            // Prosign names are at most three letters before the '>'.
+            if !name.contains('>') && name.chars().count() < 4 {
+                ready_len = ready_len.min(open);
+            }
+        }
+
+        let rest = self.pending.split_off(ready_len);
+        let ready = core::mem::replace(&mut self.pending, rest);
+        self.encode(&ready)
+    }
+
+    /// Encodes any held-back text as the end of input.
+    ///
+    /// # Returns
+    ///
+    /// Morse code for the remaining characters (possibly empty)
+    pub fn flush(&mut self) -> String {
+        let rest = core::mem::take(&mut self.pending);
+        self.encode(&rest)
+    }
+
+    /// Returns the wrapped codec.
+    pub fn codec(&self) -> &MorseCodec {
+        &self.codec
+    }
+
+    /// Encodes complete text, continuing the separators of earlier output.
+    fn encode(&mut self, text: &str) -> String {
+        let mut out = String::new();
+
+        for (_, morse) in self.codec.encode_iter(text) {
+            if self.started {
+                out.push(' ');
+            }
+            out.push_str(morse);
+            self.started = true;
+        }
+
+        out
+    }
+}
@@ -0,0 +1,583 @@
+//! Morse keying timing shared by live playback and offline rendering.
+
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::collections::hash_map::RandomState;
+#[cfg(feature = "std")]
+use std::hash::BuildHasher;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// Element and gap durations for sending Morse code.
+///
+/// Supports standard timing, where every unit is one dot long, and
+/// Farnsworth timing, where characters are sent quickly but the gaps
+/// between characters and words are stretched.
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::MorseTiming;
+///
+/// let timing = MorseTiming::new(20.0);
+/// assert_eq!(timing.dot_duration(), 60.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MorseTiming {
+    dot_duration: f64,
+    spacing_duration: f64,
+    weight: f64,
+    letter_gap_units: f64,
+    word_gap_units: f64,
+    humanize: Option<f64>,
+    seed: u64,
+}
+
+impl MorseTiming {
+    /// Creates standard timing for the given speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `wpm` - Words per minute
+    ///
+    /// # Formula
+    ///
+    /// dot_duration_ms = 1200 / WPM
+    pub fn new(wpm: f64) -> Self {
+        let dot_duration = 1200.0 / wpm;
+
+        MorseTiming {
+            dot_duration,
+            spacing_duration: dot_duration,
+            weight: 3.0,
+            letter_gap_units: 3.0,
+            word_gap_units: 7.0,
+            humanize: None,
+            seed: 0,
+        }
+    }
+
+    /// Creates Farnsworth timing.
+    ///
+    /// Characters are sent at `char_wpm` while the gaps between characters
+    /// and words are stretched so the overall speed is `effective_wpm`.
+    /// If `effective_wpm` is not slower than `char_wpm`, standard timing is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `char_wpm` - Speed at which individual characters are sent
+    /// * `effective_wpm` - Overall speed including the stretched gaps
+    ///
+    /// # Formula
+    ///
+    /// spacing_delay_s = (60 * char_wpm - 37.2 * effective_wpm) / (char_wpm * effective_wpm)
+    ///
+    /// The delay is split across the 19 spacing units of "PARIS ", so one
+    /// spacing unit lasts spacing_delay_s / 19.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseTiming;
+    ///
+    /// let timing = MorseTiming::farnsworth(20.0, 10.0);
+    /// assert_eq!(timing.dot_duration(), 60.0);
+    /// assert!(timing.spacing_duration() > timing.dot_duration());
+    /// ```
+    pub fn farnsworth(char_wpm: f64, effective_wpm: f64) -> Self {
+        let mut timing = MorseTiming::new(char_wpm);
+
+        if effective_wpm < char_wpm {
+            let delay_ms =
+                1000.0 * (60.0 * char_wpm - 37.2 * effective_wpm) / (char_wpm * effective_wpm);
+            timing.spacing_duration = delay_ms / 19.0;
+        }
+
+        timing
+    }
+
+    /// Updates the speed, resetting any Farnsworth spacing.
+    ///
+    /// # Arguments
+    ///
+    /// * `wpm` - New words per minute speed
+    pub fn set_wpm(&mut self, wpm: f64) {
+        self.dot_duration = 1200.0 / wpm;
+        self.spacing_duration = self.dot_duration;
+    }
+
+    /// Updates the dash-to-dot ratio.
+    ///
+    /// Heavier weighting lengthens dashes for readability while element
+    /// gaps stay at one unit. Values are clamped to 2.5-4.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - Dash length in dot units (3.0 is standard)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseTiming;
+    ///
+    /// let mut timing = MorseTiming::new(20.0);
+    /// timing.set_weight(3.5);
+    /// assert_eq!(timing.timeline("-")[0].duration_ms, 210.0);
+    ///
+    /// timing.set_weight(10.0);
+    /// assert_eq!(timing.weight(), 4.0);
+    /// ```
+    pub fn set_weight(&mut self, weight: f64) {
+        self.weight = weight.clamp(2.5, 4.0);
+    }
+
+    /// Updates the length of the gap between letters.
+    ///
+    /// Lengthening letter gaps while characters keep their speed is the
+    /// core of Farnsworth-style spacing. Values below 1 unit are raised to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - Letter gap in spacing units (3.0 is standard)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseTiming;
+    ///
+    /// let mut timing = MorseTiming::new(20.0);
+    /// timing.set_letter_gap_units(5.0);
+    /// assert_eq!(timing.duration_ms(". ."), 60.0 + 300.0 + 60.0);
+    /// ```
+    pub fn set_letter_gap_units(&mut self, units: f64) {
+        self.letter_gap_units = units.max(1.0);
+    }
+
+    /// Returns the length of the gap between letters, in spacing units.
+    pub fn letter_gap_units(&self) -> f64 {
+        self.letter_gap_units
+    }
+
+    /// Updates the length of the gap between words.
+    ///
+    /// Standard spacing is 7 units, but some operators and contest software
+    /// use a different word spacing. Values below 1 unit are raised to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - Word gap in spacing units (7.0 is standard)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseTiming;
+    ///
+    /// let mut timing = MorseTiming::new(20.0);
+    /// timing.set_word_gap_units(5.0);
+    /// assert_eq!(timing.duration_ms(". / ."), 60.0 + 300.0 + 60.0);
+    /// ```
+    pub fn set_word_gap_units(&mut self, units: f64) {
+        self.word_gap_units = units.max(1.0);
+    }
+
+    /// Returns the length of the gap between words, in spacing units.
+    pub fn word_gap_units(&self) -> f64 {
+        self.word_gap_units
+    }
+
+    /// Enables or disables hand-sent timing variation.
+    ///
+    /// When enabled, every element and gap is lengthened or shortened by a
+    /// random amount of up to `jitter` of its duration. The variation is
+    /// fixed by the seed, so repeated timelines (and playback) agree.
+    ///
+    /// # Arguments
+    ///
+    /// * `jitter` - Maximum variation as a fraction, clamped to 0.0-0.2
+    ///   (`None` for machine-perfect timing)
+    /// * `seed` - Seed for reproducible variation (`None` picks one at random)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseTiming;
+    ///
+    /// let mut timing = MorseTiming::new(20.0);
+    /// timing.set_humanize(Some(0.1), Some(42));
+    ///
+    /// let events = timing.timeline("... ---");
+    /// assert_eq!(events, timing.timeline("... ---"));
+    /// assert!(events[0].duration_ms != 60.0);
+    /// assert!((events[0].duration_ms - 60.0).abs() <= 6.0);
+    /// ```
+    pub fn set_humanize(&mut self, jitter: Option<f64>, seed: Option<u64>) {
+        self.humanize = jitter.map(|jitter| jitter.clamp(0.0, 0.2));
+        self.seed = seed.unwrap_or_else(random_seed);
+    }
+
+    /// Returns the hand-sent timing variation, if enabled.
+    pub fn humanize(&self) -> Option<f64> {
+        self.humanize
+    }
+
+    /// Returns the dash-to-dot ratio.
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Returns the duration of one dot in milliseconds.
+    pub fn dot_duration(&self) -> f64 {
+        self.dot_duration
+    }
+
+    /// Returns the duration of one letter/word spacing unit in milliseconds.
+    ///
+    /// Equal to the dot duration unless Farnsworth timing is in use.
+    pub fn spacing_duration(&self) -> f64 {
+        self.spacing_duration
+    }
+
+    /// Returns the speed at which individual characters are sent.
+    pub fn character_wpm(&self) -> f64 {
+        1200.0 / self.dot_duration
+    }
+
+    /// Computes the total transmission time of a Morse string.
+    ///
+    /// Uses the same timing rules as playback without scheduling any audio.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to measure
+    ///
+    /// # Returns
+    ///
+    /// Total duration in milliseconds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseTiming;
+    ///
+    /// let timing = MorseTiming::new(20.0);
+    /// assert_eq!(timing.duration_ms("... --- ..."), 1620.0);
+    /// ```
+    pub fn duration_ms(&self, morse: &str) -> f64 {
+        self.timeline(morse).last().map_or(0.0, ToneEvent::end_ms)
+    }
+
+    /// Computes the keying timeline of a Morse string.
+    ///
+    /// Adjacent silences are merged, so tone and silence events alternate.
+    /// No gap follows the final element unless the string ends with a letter
+    /// or word separator.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to lay out
+    ///
+    /// # Returns
+    ///
+    /// Every tone-on and silence span in order
+    ///
+    /// # Timing
+    ///
+    /// * Dot: 1 unit
+    /// * Dash: 3 units, or the configured weight
+    /// * Gap between elements: 1 unit
+    /// * Gap between letters: 3 units in total (space), or the configured
+    ///   letter gap
+    /// * Gap between words: 7 units in total (/), or the configured word gap
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseTiming;
+    ///
+    /// let events = MorseTiming::new(20.0).timeline(".-");
+    /// assert_eq!(events.len(), 3);
+    /// assert!(events[0].on);
+    /// assert_eq!(events[2].start_ms, 120.0);
+    /// assert_eq!(events[2].duration_ms, 180.0);
+    ///
+    /// // Each element is followed by exactly one unit of silence, and the
+    /// // letter gap tops that up to three units rather than adding to it.
+    /// let starts: Vec<f64> = MorseTiming::new(20.0)
+    ///     .timeline(".- .-")
+    ///     .iter()
+    ///     .filter(|event| event.on)
+    ///     .map(|event| event.start_ms)
+    ///     .collect();
+    /// assert_eq!(starts, [0.0, 120.0, 480.0, 600.0]);
+    /// ```
+    pub fn timeline(&self, morse: &str) -> Vec<ToneEvent> {
+        let mut events = Vec::new();
+        let mut time = 0.0;
+        // Letter and word gaps owed before the next tone. They replace the
+        // element gap rather than adding to it.
+        let mut pending = 0.0_f64;
+        let mut jitter = self.humanize.map(|amount| Jitter {
+            state: self.seed,
+            amount,
+        });
+        let mut vary = |duration: f64| match jitter.as_mut() {
+            Some(jitter) => jitter.apply(duration),
+            None => duration,
+        };
+
+        for ch in morse.chars() {
+            let tone = match ch {
+                '.' => self.dot_duration,
+                '-' => self.dot_duration * self.weight,
+                ' ' => {
+                    pending = pending.max(self.spacing_duration * self.letter_gap_units);
+                    continue;
+                }
+                '/' => {
+                    pending = pending.max(self.spacing_duration * self.word_gap_units);
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let gap = match events.last() {
+                Some(ToneEvent { on: true, .. }) => pending.max(self.dot_duration),
+                _ => pending,
+            };
+            if gap > 0.0 {
+                push_event(&mut events, &mut time, false, vary(gap));
+            }
+            push_event(&mut events, &mut time, true, vary(tone));
+            pending = 0.0;
+        }
+
+        if pending > 0.0 {
+            push_event(&mut events, &mut time, false, vary(pending));
+        }
+
+        events
+    }
+
+    /// Computes the duration of the standard word "PARIS ".
+    ///
+    /// One "PARIS " is 50 units including its trailing word gap, so at
+    /// standard timing this equals 60000 / WPM. Useful for calibrating the
+    /// speed setting.
+    ///
+    /// # Returns
+    ///
+    /// Duration in milliseconds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseTiming;
+    ///
+    /// let timing = MorseTiming::new(20.0);
+    /// assert!((timing.paris_word_duration_ms() - 60000.0 / 20.0).abs() < 1e-9);
+    /// ```
+    pub fn paris_word_duration_ms(&self) -> f64 {
+        self.duration_ms(PARIS)
+    }
+}
+
+/// Appends a span to a timeline, merging consecutive silences.
+fn push_event(events: &mut Vec<ToneEvent>, time: &mut f64, on: bool, duration_ms: f64) {
+    match events.last_mut() {
+        Some(last) if !on && !last.on => last.duration_ms += duration_ms,
+        _ => events.push(ToneEvent {
+            start_ms: *time,
+            duration_ms,
+            on,
+            frequency: None,
+            ramp: false,
+        }),
+    }
+    *time += duration_ms;
+}
+
+/// International Morse code for "PARIS " (the standard WPM word).
+const PARIS: &str = ".--. .- .-. .. ... /";
+
+/// Picks a jitter seed when the caller does not supply one.
+#[cfg(feature = "std")]
+pub(crate) fn random_seed() -> u64 {
+    RandomState::new().hash_one(0u8)
+}
+
+/// Picks a jitter seed when the caller does not supply one.
+///
+/// Without `std` there is no entropy source, so a fixed seed is used.
+#[cfg(not(feature = "std"))]
+pub(crate) fn random_seed() -> u64 {
+    0
+}
+
+/// Seeded random variation of keying durations (SplitMix64).
+struct Jitter {
+    state: u64,
+    amount: f64,
+}
+
+impl Jitter {
+    /// Scales a duration by a random factor within `1.0 ± amount`.
+    fn apply(&mut self, duration: f64) -> f64 {
+        let z = splitmix64(&mut self.state);
+        let unit = (z >> 11) as f64 / (1u64 << 53) as f64;
+        duration * (1.0 + self.amount * (2.0 * unit - 1.0))
+    }
+}
+
+/// Advances a SplitMix64 generator and returns its next output.
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A single tone or silence span in a keying timeline.
+///
+/// # Fields
+///
+/// * `start_ms` - Offset from the start of the transmission in milliseconds
+/// * `duration_ms` - Length of the span in milliseconds
+/// * `on` - true while the tone is sounding, false for silence
+/// * `frequency` - Pitch of a tone in Hz, when the timeline was laid out
+///   for a specific sidetone (`None` for silences)
+/// * `ramp` - true for the rise or fall of a tone's envelope, as laid out
+///   by `AudioPlayer::envelope_timeline`
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToneEvent {
+    pub start_ms: f64,
+    pub duration_ms: f64,
+    pub on: bool,
+    pub frequency: Option<f64>,
+    pub ramp: bool,
+}
+
+impl ToneEvent {
+    /// Returns the offset at which the span ends, in milliseconds.
+    pub fn end_ms(&self) -> f64 {
+        self.start_ms + self.duration_ms
+    }
+}
+
+/// Error returned when a timeline cannot be scaled by a factor.
+///
+/// # Fields
+///
+/// * `factor` - The rejected scale factor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaleError {
+    pub factor: f64,
+}
+
+impl fmt::Display for ScaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "scale factor {} must be positive and finite",
+            self.factor
+        )
+    }
+}
+
+impl core::error::Error for ScaleError {}
+
+/// Error returned when a speed or duration argument is out of range.
+///
+/// # Fields
+///
+/// * `parameter` - Name of the rejected argument, e.g. `"wpm"` or `"max_ms"`
+/// * `value` - The rejected value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimingError {
+    pub parameter: &'static str,
+    pub value: f64,
+}
+
+impl fmt::Display for TimingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.parameter == "wpm" {
+            write!(
+                f,
+                "speed {} WPM is outside the supported {}-{} WPM range",
+                self.value, MIN_WPM, MAX_WPM
+            )
+        } else {
+            write!(
+                f,
+                "{} {} must be positive and finite",
+                self.parameter, self.value
+            )
+        }
+    }
+}
+
+impl core::error::Error for TimingError {}
+
+/// Slowest speed accepted by the validated entry points, in WPM.
+const MIN_WPM: f64 = 1.0;
+
+/// Fastest speed accepted by the validated entry points, in WPM.
+const MAX_WPM: f64 = 60.0;
+
+/// Checks that a speed is within the supported 1-60 WPM range.
+///
+/// Zero, negative, or non-finite speeds would give infinite, negative, or
+/// NaN element lengths.
+pub(crate) fn check_wpm(wpm: f64) -> Result<(), TimingError> {
+    if !(MIN_WPM..=MAX_WPM).contains(&wpm) {
+        return Err(TimingError {
+            parameter: "wpm",
+            value: wpm,
+        });
+    }
+    Ok(())
+}
+
+/// Rescales a timeline to a different speed without recomputing it.
+///
+/// Every `start_ms` and `duration_ms` is multiplied by `factor`, so a
+/// timeline laid out at one speed can follow a live speed control. To go
+/// from `old_wpm` to `new_wpm`, use a factor of `old_wpm / new_wpm`.
+///
+/// # Arguments
+///
+/// * `events` - The timeline to rescale
+/// * `factor` - Time multiplier; above 1.0 slows down, below 1.0 speeds up
+///
+/// # Returns
+///
+/// The rescaled timeline, or a `ScaleError` if `factor` is not a positive
+/// finite number
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::{scale_timeline, MorseTiming};
+///
+/// let at_20 = MorseTiming::new(20.0).timeline("... --- ...");
+/// let at_10 = scale_timeline(&at_20, 2.0).unwrap();
+/// assert_eq!(at_10, MorseTiming::new(10.0).timeline("... --- ..."));
+///
+/// assert!(scale_timeline(&at_20, 0.0).is_err());
+/// ```
+pub fn scale_timeline(events: &[ToneEvent], factor: f64) -> Result<Vec<ToneEvent>, ScaleError> {
+    if !(factor > 0.0 && factor.is_finite()) {
+        return Err(ScaleError { factor });
+    }
+
+    Ok(events
+        .iter()
+        .map(|event| ToneEvent {
+            start_ms: event.start_ms * factor,
+            duration_ms: event.duration_ms * factor,
+            ..*event
+        })
+        .collect())
+}
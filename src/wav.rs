@@ -0,0 +1,246 @@
+//! Offline rendering of Morse code to WAV audio.
+
+use crate::fist::FistProfile;
+use crate::{MorseTiming, ToneEvent};
+use core::fmt;
+use std::f64::consts::PI;
+
+/// Lowest sample rate [`MorseWavEncoder::render_wav`] accepts, in hertz.
+const MIN_SAMPLE_RATE: u32 = 4000;
+
+/// Highest sample rate [`MorseWavEncoder::render_wav`] accepts, in hertz.
+const MAX_SAMPLE_RATE: u32 = 384_000;
+
+/// Largest sample data a WAV file can describe, in bytes.
+///
+/// The RIFF header stores the file size minus 8 as a `u32`, and 36 of
+/// those bytes are header.
+const MAX_DATA_LEN: u64 = u32::MAX as u64 - 36;
+
+/// Error returned when Morse code cannot be rendered to a WAV file.
+///
+/// # Fields
+///
+/// * `sample_rate` - The requested sample rate in hertz
+/// * `data_len` - Size the sample data would take in bytes, or 0 if the
+///   sample rate itself was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WavError {
+    pub sample_rate: u32,
+    pub data_len: u64,
+}
+
+impl fmt::Display for WavError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.data_len == 0 {
+            write!(
+                f,
+                "sample rate {} Hz is outside the supported {}-{} Hz range",
+                self.sample_rate, MIN_SAMPLE_RATE, MAX_SAMPLE_RATE
+            )
+        } else {
+            write!(
+                f,
+                "{} bytes of samples exceed the WAV limit of {} bytes",
+                self.data_len, MAX_DATA_LEN
+            )
+        }
+    }
+}
+
+impl core::error::Error for WavError {}
+
+/// Pure-Rust Morse code WAV renderer.
+///
+/// Renders the keying timeline of [`AudioPlayer`](crate::AudioPlayer) into
+/// a mono 16-bit PCM WAV buffer, as an 800 Hz sine wave at 0.3 volume.
+/// Tones are keyed hard on and off, without the player's rise and fall
+/// ramps, and the player's pitch, waveform, and volume settings do not
+/// apply. It does not depend on the Web Audio API, so it works in native
+/// builds and on servers.
+///
+/// # Example
+///
+/// ```rust
+/// use morsewave::MorseWavEncoder;
+///
+/// let encoder = MorseWavEncoder::new(20.0);
+/// let wav = encoder.render_wav("... --- ...", 8000).unwrap();
+/// assert_eq!(&wav[0..4], b"RIFF");
+/// assert_eq!(&wav[8..12], b"WAVE");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MorseWavEncoder {
+    timing: MorseTiming,
+    frequency: f64,
+    volume: f64,
+    fist: Option<FistProfile>,
+}
+
+impl MorseWavEncoder {
+    /// Creates a new encoder with standard timing at the given speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `wpm` - Words per minute
+    pub fn new(wpm: f64) -> Self {
+        Self::with_timing(MorseTiming::new(wpm))
+    }
+
+    /// Creates a new encoder with custom timing, such as Farnsworth spacing.
+    ///
+    /// # Arguments
+    ///
+    /// * `timing` - Element and gap durations to render with
+    pub fn with_timing(timing: MorseTiming) -> Self {
+        MorseWavEncoder {
+            timing,
+            frequency: 800.0,
+            volume: 0.3,
+            fist: None,
+        }
+    }
+
+    /// Enables or disables an operator-like loudness pattern.
+    ///
+    /// Each element is rendered slightly softer depending on its position
+    /// in the letter, following a pattern fixed by the seed, which imitates
+    /// the characteristic sending ("fist") of one operator. Off by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - Maximum softening as a fraction of the volume, clamped
+    ///   to 0.0-0.5 (`None` for an even volume)
+    /// * `seed` - Seed selecting the pattern (`None` picks one at random)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseWavEncoder;
+    ///
+    /// let mut encoder = MorseWavEncoder::new(20.0);
+    /// let even = encoder.render_wav("..", 8000).unwrap();
+    ///
+    /// encoder.set_fist_profile(Some(0.3), Some(7));
+    /// let fist = encoder.render_wav("..", 8000).unwrap();
+    /// assert_eq!(fist, encoder.render_wav("..", 8000).unwrap());
+    /// assert_ne!(fist, even);
+    /// ```
+    pub fn set_fist_profile(&mut self, depth: Option<f64>, seed: Option<u64>) {
+        self.fist = depth.map(|depth| FistProfile::new(depth, seed));
+    }
+
+    /// Renders Morse code to a complete WAV file.
+    ///
+    /// # Arguments
+    ///
+    /// * `morse` - Morse code string to render
+    /// * `sample_rate` - Output sample rate in hertz (4000-384000), used
+    ///   for both the header and the synthesized samples
+    ///
+    /// # Returns
+    ///
+    /// WAV file bytes (RIFF header followed by mono 16-bit PCM samples), or
+    /// a `WavError` if the sample rate is outside 4000-384000 Hz or the
+    /// samples would not fit in a WAV file
+    ///
+    /// # Audio Properties
+    ///
+    /// * Frequency: 800 Hz sine wave
+    /// * Volume: 0.3 gain
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use morsewave::MorseWavEncoder;
+    ///
+    /// let encoder = MorseWavEncoder::new(20.0);
+    /// for rate in [8000, 22050, 44100] {
+    ///     let wav = encoder.render_wav(".", rate).unwrap();
+    ///     let stored = u32::from_le_bytes(wav[24..28].try_into().unwrap());
+    ///     let byte_rate = u32::from_le_bytes(wav[28..32].try_into().unwrap());
+    ///     let data_len = u32::from_le_bytes(wav[40..44].try_into().unwrap());
+    ///
+    ///     assert_eq!(stored, rate);
+    ///     assert_eq!(byte_rate, rate * 2);
+    ///     // One dot at 20 WPM lasts 60 ms.
+    ///     assert_eq!(data_len, (rate as f64 * 0.06).round() as u32 * 2);
+    /// }
+    ///
+    /// let err = encoder.render_wav(".", 3999).unwrap_err();
+    /// assert_eq!(err.sample_rate, 3999);
+    ///
+    /// // Hours of slow dots at the highest rate would need more
+    /// // than the 4 GiB a WAV file can hold.
+    /// let slow = MorseWavEncoder::new(1.0);
+    /// let err = slow.render_wav(&". ".repeat(2000), 384_000).unwrap_err();
+    /// assert!(err.data_len > u64::from(u32::MAX));
+    /// ```
+    pub fn render_wav(&self, morse: &str, sample_rate: u32) -> Result<Vec<u8>, WavError> {
+        let rejected = |data_len| WavError {
+            sample_rate,
+            data_len,
+        };
+        if !(MIN_SAMPLE_RATE..=MAX_SAMPLE_RATE).contains(&sample_rate) {
+            return Err(rejected(0));
+        }
+
+        // Check the size before rendering, so an oversized file fails
+        // instead of allocating its samples.
+        let duration_ms = self.timing.duration_ms(morse);
+        let samples = (duration_ms * f64::from(sample_rate) / 1000.0).round();
+        let data_len = (samples * 2.0) as u64;
+        if data_len > MAX_DATA_LEN {
+            return Err(rejected(data_len));
+        }
+        let data_len = u32::try_from(data_len).map_err(|_| rejected(data_len))?;
+
+        let samples = self.render_samples(morse, sample_rate);
+
+        let mut wav = Vec::with_capacity(44 + data_len as usize);
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        wav.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        for sample in samples {
+            wav.extend_from_slice(&sample.to_le_bytes());
+        }
+
+        Ok(wav)
+    }
+
+    fn render_samples(&self, morse: &str, sample_rate: u32) -> Vec<i16> {
+        let rate = f64::from(sample_rate);
+        let to_sample = |ms: f64| (ms * rate / 1000.0).round() as usize;
+
+        let timeline = self.timing.timeline(morse);
+        let total_ms = timeline.last().map_or(0.0, ToneEvent::end_ms);
+        let mut samples = vec![0i16; to_sample(total_ms)];
+        let mut gains = self.fist.iter().flat_map(|fist| fist.gains(morse));
+
+        for event in timeline.iter().filter(|event| event.on) {
+            let start = to_sample(event.start_ms);
+            let end = to_sample(event.end_ms()).min(samples.len());
+            let volume = self.volume * gains.next().unwrap_or(1.0);
+
+            for (i, sample) in samples[start..end].iter_mut().enumerate() {
+                let t = i as f64 / rate;
+                let value = (2.0 * PI * self.frequency * t).sin() * volume;
+                *sample = (value * f64::from(i16::MAX)) as i16;
+            }
+        }
+
+        samples
+    }
+}